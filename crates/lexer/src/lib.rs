@@ -1,6 +1,6 @@
-use std::{fmt, ops::Range};
+use std::{collections::VecDeque, fmt, ops::Range};
 
-use logos::{Logos, Skip};
+use logos::{Lexer, Logos, Skip};
 
 #[derive(Default, Clone, Debug, PartialEq, Eq, Hash)]
 pub enum LexingError {
@@ -13,168 +13,191 @@ pub enum LexingError {
 #[logos(skip r"[ \t\n\f\r]+")]
 pub enum Token<'a> {
     Unknown,
-    #[token("<?php")]
+    // NOTE: Start tags and inline HTML are produced by `ModalLexer`
     ScriptSectionStartTag,
-    #[token("?>")]
+    #[token("?>", script_section_end_tag)]
     ScriptSectionEndTag,
-    #[token("<?=")]
     ScriptSectionStartWithEchoTag,
+    InlineHtml(&'a str),
 
     // TODO: Later we should add it
     #[regex(r#"\/\*([^*]|\*[^\/])*\*\/"#, |_| Skip)]
-    #[regex(r#"#[^\r\n]*"#, |_| Skip)]
-    #[regex(r#"//[^\r\n]*"#, |_| Skip)]
+    #[token("#", line_comment)]
+    #[token("//", line_comment)]
     Comment,
 
-    #[regex("[a-zA-Z_]+")] // name-nondigit
+    // NOTE: PHP accepts any byte from 0x80 in names, which covers every non-ASCII character
+    #[regex(r#"[a-zA-Z_\x{80}-\x{10FFFF}][a-zA-Z0-9_\x{80}-\x{10FFFF}]*"#)]
     Name(&'a str),
-    #[regex(r#"\$[a-zA-Z_][_a-zA-Z0-9]*"#)]
+    // NOTE: Like PHP 8, names with namespace separators are single tokens
+    // `App\Foo`
+    #[regex(r#"[a-zA-Z_\x{80}-\x{10FFFF}][a-zA-Z0-9_\x{80}-\x{10FFFF}]*(\\[a-zA-Z_\x{80}-\x{10FFFF}][a-zA-Z0-9_\x{80}-\x{10FFFF}]*)+"#)]
+    QualifiedName(&'a str),
+    // `\App\Foo`
+    #[regex(r#"(\\[a-zA-Z_\x{80}-\x{10FFFF}][a-zA-Z0-9_\x{80}-\x{10FFFF}]*)+"#)]
+    FullyQualifiedName(&'a str),
+    // `namespace\Foo`
+    #[regex(
+        r#"(?i:namespace)(\\[a-zA-Z_\x{80}-\x{10FFFF}][a-zA-Z0-9_\x{80}-\x{10FFFF}]*)+"#,
+        priority = 20
+    )]
+    RelativeName(&'a str),
+    #[regex(r#"\$[a-zA-Z_\x{80}-\x{10FFFF}][a-zA-Z0-9_\x{80}-\x{10FFFF}]*"#)]
     VariableName(&'a str),
 
     // KEYWORDS
+    // NOTE: Keywords carry their source text as they can also be used as names
     #[token("abstract", ignore(case))]
-    AbstractKeyword,
+    AbstractKeyword(&'a str),
     #[token("and", ignore(case))]
-    AndKeyword,
+    AndKeyword(&'a str),
     #[token("array", ignore(case))]
-    ArrayKeyword,
+    ArrayKeyword(&'a str),
     #[token("as", ignore(case))]
-    AsKeyword,
+    AsKeyword(&'a str),
     #[token("break", ignore(case))]
-    BreakKeyword,
+    BreakKeyword(&'a str),
     #[token("callable", ignore(case))]
-    CallableKeyword,
+    CallableKeyword(&'a str),
     #[token("case", ignore(case))]
-    CaseKeyword,
+    CaseKeyword(&'a str),
     #[token("catch", ignore(case))]
-    CatchKeyword,
+    CatchKeyword(&'a str),
     #[token("class", ignore(case))]
-    ClassKeyword,
+    ClassKeyword(&'a str),
     #[token("clone", ignore(case))]
-    CloneKeyword,
+    CloneKeyword(&'a str),
     #[token("const", ignore(case))]
-    ConstKeyword,
+    ConstKeyword(&'a str),
     #[token("continue", ignore(case))]
-    ContinueKeyword,
+    ContinueKeyword(&'a str),
     #[token("declare", ignore(case))]
-    DeclareKeyword,
+    DeclareKeyword(&'a str),
     #[token("default", ignore(case))]
-    DefaultKeyword,
+    DefaultKeyword(&'a str),
     #[token("die", ignore(case))]
-    DieKeyword,
+    DieKeyword(&'a str),
     #[token("do", ignore(case))]
-    DoKeyword,
+    DoKeyword(&'a str),
     #[token("echo", ignore(case))]
-    EchoKeyword,
+    EchoKeyword(&'a str),
     #[token("else", ignore(case))]
-    ElseKeyword,
+    ElseKeyword(&'a str),
     #[token("elseif", ignore(case))]
-    ElseIfKeyword,
+    ElseIfKeyword(&'a str),
     #[token("empty", ignore(case))]
-    EmptyKeyword,
+    EmptyKeyword(&'a str),
     #[token("enddeclare", ignore(case))]
-    EndDeclareKeyword,
+    EndDeclareKeyword(&'a str),
     #[token("endfor", ignore(case))]
-    EndForKeyword,
+    EndForKeyword(&'a str),
     #[token("endforeach", ignore(case))]
-    EndForEachKeyword,
+    EndForEachKeyword(&'a str),
     #[token("endif", ignore(case))]
-    EndIfKeyword,
+    EndIfKeyword(&'a str),
     #[token("endswitch", ignore(case))]
-    EndSwitchKeyword,
+    EndSwitchKeyword(&'a str),
     #[token("endwhile", ignore(case))]
-    EndWhileKeyword,
+    EndWhileKeyword(&'a str),
     #[token("eval", ignore(case))]
-    EvalKeyword,
+    EvalKeyword(&'a str),
     #[token("exit", ignore(case))]
-    ExitKeyword,
+    ExitKeyword(&'a str),
     #[token("extends", ignore(case))]
-    ExtendsKeyword,
+    ExtendsKeyword(&'a str),
     #[token("final", ignore(case))]
-    FinalKeyword,
+    FinalKeyword(&'a str),
     #[token("finally", ignore(case))]
-    FinallyKeyword,
+    FinallyKeyword(&'a str),
     #[token("for", ignore(case))]
-    ForKeyword,
+    ForKeyword(&'a str),
     #[token("foreach", ignore(case))]
-    ForeachKeyword,
+    ForeachKeyword(&'a str),
     #[token("function", ignore(case))]
-    FunctionKeyword,
+    FunctionKeyword(&'a str),
     #[token("global", ignore(case))]
-    GlobalKeyword,
+    GlobalKeyword(&'a str),
     #[token("goto", ignore(case))]
-    GotoKeyword,
+    GotoKeyword(&'a str),
     #[token("if", ignore(case))]
-    IfKeyword,
+    IfKeyword(&'a str),
     #[token("implements", ignore(case))]
-    ImplementsKeyword,
+    ImplementsKeyword(&'a str),
     #[token("include", ignore(case))]
-    IncludeKeyword,
+    IncludeKeyword(&'a str),
     #[token("include_once", ignore(case))]
-    IncludeOnceKeyword,
-    #[token("instance_of", ignore(case))]
-    InstanceOfKeyword,
-    #[token("instead_of", ignore(case))]
-    InsteadOfKeyword,
+    IncludeOnceKeyword(&'a str),
+    #[token("instanceof", ignore(case))]
+    InstanceOfKeyword(&'a str),
+    #[token("insteadof", ignore(case))]
+    InsteadOfKeyword(&'a str),
     #[token("interface", ignore(case))]
-    InterfaceKeyword,
+    InterfaceKeyword(&'a str),
     #[token("isset", ignore(case))]
-    IssetKeyword,
+    IssetKeyword(&'a str),
     #[token("list", ignore(case))]
-    ListKeyword,
+    ListKeyword(&'a str),
     #[token("namespace", ignore(case))]
-    NamespaceKeyword,
+    NamespaceKeyword(&'a str),
     #[token("new", ignore(case))]
-    NewKeyword,
+    NewKeyword(&'a str),
     #[token("or", ignore(case))]
-    OrKeyword,
+    OrKeyword(&'a str),
     #[token("print", ignore(case))]
-    PrintKeyword,
+    PrintKeyword(&'a str),
     #[token("private", ignore(case))]
-    PrivateKeyword,
+    PrivateKeyword(&'a str),
     #[token("protected", ignore(case))]
-    ProtectedKeyword,
+    ProtectedKeyword(&'a str),
     #[token("public", ignore(case))]
-    PublicKeyword,
+    PublicKeyword(&'a str),
+    // NOTE: Asymmetric visibility, PHP lexes `private(set)` as a single token
+    #[token("private(set)", ignore(case))]
+    PrivateSetKeyword,
+    #[token("protected(set)", ignore(case))]
+    ProtectedSetKeyword,
+    #[token("public(set)", ignore(case))]
+    PublicSetKeyword,
     #[token("require", ignore(case))]
-    RequireKeyword,
+    RequireKeyword(&'a str),
     #[token("require_once", ignore(case))]
-    RequireOnceKeyword,
+    RequireOnceKeyword(&'a str),
     #[token("return", ignore(case))]
-    ReturnKeyword,
+    ReturnKeyword(&'a str),
     #[token("static", ignore(case))]
-    StaticKeyword,
+    StaticKeyword(&'a str),
     #[token("switch", ignore(case))]
-    SwitchKeyword,
+    SwitchKeyword(&'a str),
     #[token("throw", ignore(case))]
-    ThrowKeyword,
+    ThrowKeyword(&'a str),
     #[token("trait", ignore(case))]
-    TraitKeyword,
+    TraitKeyword(&'a str),
     #[token("try", ignore(case))]
-    TryKeyword,
+    TryKeyword(&'a str),
     #[token("unset", ignore(case))]
-    UnsetKeyword,
+    UnsetKeyword(&'a str),
     #[token("use", ignore(case))]
-    UseKeyword,
+    UseKeyword(&'a str),
     #[token("var", ignore(case))]
-    VarKeyword,
+    VarKeyword(&'a str),
     #[token("while", ignore(case))]
-    WhileKeyword,
+    WhileKeyword(&'a str),
     #[token("xor", ignore(case))]
-    XorKeyword,
-    // WARNING: idk why but if yield is followed by 0x0a (LF) it does not match
-    #[regex("yield[\r\n ]?", ignore(case))]
-    YieldKeyword,
-    #[regex(r#"yield[\t\n\f ]from"#, ignore(case))]
+    XorKeyword(&'a str),
+    // NOTE: `yield from` is detected in the callback as logos cannot backtrack
+    // out of a partially matched `yield <whitespace>` prefix. The callback produces both
+    // tokens, it is attached to the unit variant as logos only lets those return a `Token`
+    YieldKeyword(&'a str),
+    #[token("yield", yield_keyword, ignore(case))]
     YieldFromKeyword,
     #[token("fn", ignore(case))]
-    FnKeyword,
+    FnKeyword(&'a str),
     #[token("match", ignore(case))]
-    MatchKeyword,
+    MatchKeyword(&'a str),
     #[token("enum", ignore(case))]
-    EnumKeyword,
+    EnumKeyword(&'a str),
     #[token("readonly", ignore(case))]
-    ReadonlyKeyword,
+    ReadonlyKeyword(&'a str),
     // TODO: This should not be a keyword
     // #[token("halt")]
     // HaltCompilerKeyword,
@@ -184,7 +207,7 @@ pub enum Token<'a> {
     OpenBracket,
     #[token("]")]
     CloseBracket,
-    #[token("(")]
+    #[token("(", open_paren)]
     OpenParen,
     #[token(")")]
     CloseParen,
@@ -304,38 +327,85 @@ pub enum Token<'a> {
     QuestionQuestionEquals,
     #[token("?->")]
     QuestionArrow,
-    // TODO: Idk
-    // #[token("#")]
-    // Attribute,
+    // NOTE: Takes precedence over `#` comments
+    #[token("#[")]
+    Attribute,
     #[token("'")]
     SingleQuote,
-    #[token(r#"""#)]
+    #[regex(r#"[bB]?""#, double_quote)]
     DoubleQuote,
     #[token("${")]
     DollarOpenBrace,
-    #[token("{$")]
+    // NOTE: Only produced inside interpolated strings, see `ModalLexer`
     OpenBraceDollar,
 
+    // CASTS
+    // NOTE: Produced by the `(` callback, see `open_paren`
+    ArrayCast,
+    BoolCast,
+    FloatCast,
+    IntCast,
+    ObjectCast,
+    StringCast,
+    UnsetCast,
+
     // LITERALS
     // TODO: Add others
-    #[regex(r#"[bB]?"(?:\\.|[^"\\])*""#)] // TODO: Improve this
+    // NOTE: Double quoted strings are produced by the `"` callback, see `double_quote`
     #[regex(r#"[bB]?'(?:\\.|[^'\\])*'"#)] // TODO: Improve this
     StringLiteral(&'a str),
+    // NOTE: Heredoc and nowdoc without interpolation are lexed as a whole into a `StringLiteral`
+    #[token("<<<", heredoc)]
+    HeredocStart,
+    // NOTE: Includes the newline before the closing marker and its indentation
+    HeredocEnd(&'a str),
+
+    // INTERPOLATED STRINGS
+    // NOTE: Produced by `ModalLexer`
+    EncapsedAndWhitespace(&'a str),
+    StringVarname(&'a str),
 
-    #[regex(r#"(0x)?[0-9]+"#, priority = 11)]
+    // NOTE: Decimal, octal (`017` or `0o17`) and hexadecimal, digits may be separated by `_`
+    #[regex(r#"[0-9]+(_[0-9]+)*"#, priority = 11)]
+    #[regex(r#"0[oO][0-7]+(_[0-7]+)*"#, priority = 11)]
+    #[regex(r#"0[xX][0-9a-fA-F]+(_[0-9a-fA-F]+)*"#, priority = 11)]
     IntegerLiteral(&'a str),
 
-    // #[regex(r#"[.][0-9]+"#)]
-    #[regex(r#"[0-9]*[.][0-9]+([eE][+-]?[0-9]+)?"#, priority = 12)]
-    #[regex(r#"[0-9]+[.][0-9]*([eE][+-]?[0-9]+)?"#, priority = 13)]
+    #[regex(
+        r#"([0-9]+(_[0-9]+)*)?[.][0-9]+(_[0-9]+)*([eE][+-]?[0-9]+(_[0-9]+)*)?"#,
+        priority = 12
+    )]
+    #[regex(
+        r#"[0-9]+(_[0-9]+)*[.]([0-9]+(_[0-9]+)*)?([eE][+-]?[0-9]+(_[0-9]+)*)?"#,
+        priority = 13
+    )]
+    #[regex(r#"[0-9]+(_[0-9]+)*[eE][+-]?[0-9]+(_[0-9]+)*"#, priority = 13)]
     FloatingLiteral(&'a str),
 
-    #[regex(r#"0[bB][01]+"#, priority = 7)]
+    #[regex(r#"0[bB][01]+(_[01]+)*"#, priority = 7)]
     BinaryLiteral(&'a str),
 
-    #[regex(r#"0[bB][0-9]+"#, priority = 6)]
+    #[regex(r#"0[bB][0-9]+(_[0-9]+)*"#, priority = 6)]
     InvalidBinaryLiteral(&'a str),
 
+    // MAGIC CONSTANTS
+    #[token("__LINE__", ignore(case))]
+    LineMagicConstant,
+    #[token("__FILE__", ignore(case))]
+    FileMagicConstant,
+    #[token("__DIR__", ignore(case))]
+    DirMagicConstant,
+    #[token("__CLASS__", ignore(case))]
+    ClassMagicConstant,
+    #[token("__FUNCTION__", ignore(case))]
+    FunctionMagicConstant,
+    #[token("__METHOD__", ignore(case))]
+    MethodMagicConstant,
+    #[token("__NAMESPACE__", ignore(case))]
+    NamespaceMagicConstant,
+    #[token("__TRAIT__", ignore(case))]
+    TraitMagicConstant,
+
     // RESERVED WORDS
     // TODO: Check if capitalized
     #[token("int")]
@@ -343,9 +413,9 @@ pub enum Token<'a> {
     #[token("float")]
     FloatReservedWord,
     #[token("true", ignore(case))]
-    TrueReservedWord,
+    TrueReservedWord(&'a str),
     #[token("false", ignore(case))]
-    FalseReservedWord,
+    FalseReservedWord(&'a str),
     #[token("string")]
     StringReservedWord,
     #[token("bool")]
@@ -387,7 +457,6 @@ pub enum Token<'a> {
 
     // TODO: What is this??
     ReturnType,
-    ScriptSectionPrependText,
 
     // Manually added
     EndOfFile,
@@ -403,10 +472,636 @@ impl<'a> fmt::Display for Token<'a> {
     }
 }
 
+impl<'a> Token<'a> {
+    // Spelling of the keywords and reserved words PHP accepts as member, constant and enum
+    // case names
+    // NOTE: Case-insensitive keywords keep the spelling used in the source
+    pub fn keyword(&self) -> Option<&'a str> {
+        let keyword = match self {
+            Self::AbstractKeyword(keyword)
+            | Self::AndKeyword(keyword)
+            | Self::ArrayKeyword(keyword)
+            | Self::AsKeyword(keyword)
+            | Self::BreakKeyword(keyword)
+            | Self::CallableKeyword(keyword)
+            | Self::CaseKeyword(keyword)
+            | Self::CatchKeyword(keyword)
+            | Self::ClassKeyword(keyword)
+            | Self::CloneKeyword(keyword)
+            | Self::ConstKeyword(keyword)
+            | Self::ContinueKeyword(keyword)
+            | Self::DeclareKeyword(keyword)
+            | Self::DefaultKeyword(keyword)
+            | Self::DieKeyword(keyword)
+            | Self::DoKeyword(keyword)
+            | Self::EchoKeyword(keyword)
+            | Self::ElseKeyword(keyword)
+            | Self::ElseIfKeyword(keyword)
+            | Self::EmptyKeyword(keyword)
+            | Self::EndDeclareKeyword(keyword)
+            | Self::EndForKeyword(keyword)
+            | Self::EndForEachKeyword(keyword)
+            | Self::EndIfKeyword(keyword)
+            | Self::EndSwitchKeyword(keyword)
+            | Self::EndWhileKeyword(keyword)
+            | Self::EvalKeyword(keyword)
+            | Self::ExitKeyword(keyword)
+            | Self::ExtendsKeyword(keyword)
+            | Self::FinalKeyword(keyword)
+            | Self::FinallyKeyword(keyword)
+            | Self::ForKeyword(keyword)
+            | Self::ForeachKeyword(keyword)
+            | Self::FunctionKeyword(keyword)
+            | Self::GlobalKeyword(keyword)
+            | Self::GotoKeyword(keyword)
+            | Self::IfKeyword(keyword)
+            | Self::ImplementsKeyword(keyword)
+            | Self::IncludeKeyword(keyword)
+            | Self::IncludeOnceKeyword(keyword)
+            | Self::InstanceOfKeyword(keyword)
+            | Self::InsteadOfKeyword(keyword)
+            | Self::InterfaceKeyword(keyword)
+            | Self::IssetKeyword(keyword)
+            | Self::ListKeyword(keyword)
+            | Self::NamespaceKeyword(keyword)
+            | Self::NewKeyword(keyword)
+            | Self::OrKeyword(keyword)
+            | Self::PrintKeyword(keyword)
+            | Self::PrivateKeyword(keyword)
+            | Self::ProtectedKeyword(keyword)
+            | Self::PublicKeyword(keyword)
+            | Self::RequireKeyword(keyword)
+            | Self::RequireOnceKeyword(keyword)
+            | Self::ReturnKeyword(keyword)
+            | Self::StaticKeyword(keyword)
+            | Self::SwitchKeyword(keyword)
+            | Self::ThrowKeyword(keyword)
+            | Self::TraitKeyword(keyword)
+            | Self::TryKeyword(keyword)
+            | Self::UnsetKeyword(keyword)
+            | Self::UseKeyword(keyword)
+            | Self::VarKeyword(keyword)
+            | Self::WhileKeyword(keyword)
+            | Self::XorKeyword(keyword)
+            | Self::YieldKeyword(keyword)
+            | Self::FnKeyword(keyword)
+            | Self::MatchKeyword(keyword)
+            | Self::EnumKeyword(keyword)
+            | Self::ReadonlyKeyword(keyword)
+            | Self::TrueReservedWord(keyword)
+            | Self::FalseReservedWord(keyword) => keyword,
+            Self::IntReservedWord => "int",
+            Self::FloatReservedWord => "float",
+            Self::StringReservedWord => "string",
+            Self::BoolReservedWord => "bool",
+            Self::NullReservedWord => "null",
+            Self::MixedReservedWord => "mixed",
+            Self::IterableReservedWord => "iterable",
+            Self::NeverReservedWord => "never",
+            Self::VoidReservedWord => "void",
+            Self::BinaryReservedWord => "binary",
+            Self::BooleanReservedWord => "boolean",
+            Self::DoubleReservedWord => "double",
+            Self::IntegerReservedWord => "integer",
+            Self::ObjectReservedWord => "object",
+            Self::RealReservedWord => "real",
+            Self::SelfKeyword => "self",
+            Self::ParentKeyworkd => "parent",
+            Self::ConstructKeyword => "__construct",
+            Self::DestructKeyword => "__destruct",
+            _ => return None,
+        };
+
+        Some(keyword)
+    }
+}
+
+fn yield_keyword<'a>(lex: &mut Lexer<'a, Token<'a>>) -> Token<'a> {
+    let remainder = lex.remainder();
+    let rest = remainder.trim_start_matches([' ', '\t', '\n', '\x0C', '\r']);
+    let whitespace = remainder.len() - rest.len();
+
+    let is_from = whitespace > 0
+        && rest.len() >= 4
+        && rest.as_bytes()[..4].eq_ignore_ascii_case(b"from")
+        && !rest
+            .as_bytes()
+            .get(4)
+            .is_some_and(|c| c.is_ascii_alphanumeric() || *c == b'_' || *c >= 0x80);
+
+    if is_from {
+        lex.bump(whitespace + 4);
+        Token::YieldFromKeyword
+    } else {
+        Token::YieldKeyword(lex.slice())
+    }
+}
+
+fn open_paren<'a>(lex: &mut Lexer<'a, Token<'a>>) -> Token<'a> {
+    let remainder = lex.remainder();
+    let is_blank = |c: char| c == ' ' || c == '\t';
+
+    let inner = remainder.trim_start_matches(is_blank);
+    let name_len = inner
+        .find(|c: char| !c.is_ascii_alphabetic())
+        .unwrap_or(inner.len());
+    let (name, rest) = inner.split_at(name_len);
+    let rest = rest.trim_start_matches(is_blank);
+
+    if !rest.starts_with(')') {
+        return Token::OpenParen;
+    }
+
+    let token = match name.to_ascii_lowercase().as_str() {
+        "array" => Token::ArrayCast,
+        "bool" | "boolean" => Token::BoolCast,
+        "float" | "double" | "real" => Token::FloatCast,
+        "int" | "integer" => Token::IntCast,
+        "object" => Token::ObjectCast,
+        "string" | "binary" => Token::StringCast,
+        "unset" => Token::UnsetCast,
+        _ => return Token::OpenParen,
+    };
+
+    lex.bump(remainder.len() - rest.len() + 1);
+    token
+}
+
+// A single newline directly following `?>` is part of the tag
+fn script_section_end_tag<'a>(lex: &mut Lexer<'a, Token<'a>>) {
+    let newline = match lex.remainder().as_bytes() {
+        [b'\n', ..] => 1,
+        [b'\r', b'\n', ..] => 2,
+        _ => 0,
+    };
+
+    lex.bump(newline);
+}
+
+// Single line comments end at the end of the line or before a `?>`
+fn line_comment<'a>(lex: &mut Lexer<'a, Token<'a>>) -> Skip {
+    let remainder = lex.remainder();
+    let end = remainder.find(['\r', '\n']).unwrap_or(remainder.len());
+    let end = remainder[..end].find("?>").unwrap_or(end);
+
+    lex.bump(end);
+    Skip
+}
+
+fn is_label_char(c: u8) -> bool {
+    c.is_ascii_alphanumeric() || c == b'_' || c >= 0x80
+}
+
+fn label_len(text: &str) -> usize {
+    match text.bytes().next() {
+        Some(c) if !c.is_ascii_digit() && is_label_char(c) => {
+            text.bytes().take_while(|c| is_label_char(*c)).count()
+        }
+        _ => 0,
+    }
+}
+
+// Length of the heredoc closing marker (including its indentation) at the start of `line`
+fn closing_marker(line: &str, label: &str) -> Option<usize> {
+    let indentation = line.len() - line.trim_start_matches([' ', '\t']).len();
+    let rest = line[indentation..].strip_prefix(label)?;
+
+    match rest.bytes().next().is_some_and(is_label_char) {
+        true => None,
+        false => Some(indentation + label.len()),
+    }
+}
+
+// Whether `bytes` start with `$name`, `${` or `{$`
+fn starts_interpolation(bytes: &[u8]) -> bool {
+    match bytes {
+        [b'$', b'{', ..] | [b'{', b'$', ..] => true,
+        [b'$', c, ..] => !c.is_ascii_digit() && is_label_char(*c),
+        _ => false,
+    }
+}
+
+fn has_interpolation(text: &str) -> bool {
+    let bytes = text.as_bytes();
+    let mut pos = 0;
+
+    while pos < bytes.len() {
+        match bytes[pos] {
+            b'\\' => pos += 2,
+            _ if starts_interpolation(&bytes[pos..]) => return true,
+            _ => pos += 1,
+        }
+    }
+
+    false
+}
+
+// Double quoted strings without interpolation are lexed as a whole into a
+// `StringLiteral`, the others are lexed by `ModalLexer`.
+fn double_quote<'a>(lex: &mut Lexer<'a, Token<'a>>) -> Token<'a> {
+    let remainder = lex.remainder();
+    let bytes = remainder.as_bytes();
+    let mut pos = 0;
+
+    while pos < bytes.len() {
+        match bytes[pos] {
+            b'\\' => pos += 2,
+            b'"' => {
+                lex.bump(pos + 1);
+                return Token::StringLiteral(lex.slice());
+            }
+            _ if starts_interpolation(&bytes[pos..]) => break,
+            _ => pos += 1,
+        }
+    }
+
+    Token::DoubleQuote
+}
+
+// Lexes a whole heredoc/nowdoc (`<<<EOT ... EOT`) as a single string literal,
+// unless the heredoc body contains interpolations.
+// The closing marker may be indented (PHP >= 7.3), in which case every line of
+// the body must be indented with at least the same whitespace.
+fn heredoc<'a>(lex: &mut Lexer<'a, Token<'a>>) -> std::result::Result<Token<'a>, LexingError> {
+    let remainder = lex.remainder();
+    let bytes = remainder.as_bytes();
+
+    let mut pos = remainder.len() - remainder.trim_start_matches([' ', '\t']).len();
+
+    let quote = match bytes.get(pos) {
+        Some(c @ (b'\'' | b'"')) => {
+            pos += 1;
+            Some(*c)
+        }
+        _ => None,
+    };
+
+    let label_start = pos;
+    if !bytes
+        .get(pos)
+        .is_some_and(|c| !c.is_ascii_digit() && is_label_char(*c))
+    {
+        return Err(LexingError::Unknown);
+    }
+    while bytes.get(pos).is_some_and(|c| is_label_char(*c)) {
+        pos += 1;
+    }
+    let label = &remainder[label_start..pos];
+
+    if let Some(quote) = quote {
+        if bytes.get(pos) != Some(&quote) {
+            return Err(LexingError::Unknown);
+        }
+        pos += 1;
+    }
+
+    pos += match (bytes.get(pos), bytes.get(pos + 1)) {
+        (Some(b'\n'), _) => 1,
+        (Some(b'\r'), Some(b'\n')) => 2,
+        _ => return Err(LexingError::Unknown),
+    };
+
+    let body_start = pos;
+    loop {
+        let line = &remainder[pos..];
+
+        if let Some(marker) = closing_marker(line, label) {
+            let closing_indentation = &line[..marker - label.len()];
+            let body = &remainder[body_start..pos];
+
+            let invalid_indentation = body
+                .lines()
+                .any(|line| !line.trim().is_empty() && !line.starts_with(closing_indentation));
+
+            if invalid_indentation {
+                lex.bump(pos + marker);
+                return Err(LexingError::Unknown);
+            }
+
+            // The body is lexed by `ModalLexer`
+            if quote != Some(b'\'') && has_interpolation(body) {
+                lex.bump(body_start);
+                return Ok(Token::HeredocStart);
+            }
+
+            lex.bump(pos + marker);
+            return Ok(Token::StringLiteral(lex.slice()));
+        }
+
+        match line.find('\n') {
+            Some(end) => pos += end + 1,
+            None => {
+                lex.bump(remainder.len());
+                return Err(LexingError::Unknown);
+            }
+        }
+    }
+}
+
 pub type Result<'a> = std::result::Result<Token<'a>, LexingError>;
 
-pub fn lexer(content: &str) -> impl Iterator<Item = (Result, Range<usize>)> {
-    Token::lexer(content)
-        .spanned()
-        .chain(Some((Ok(Token::EndOfFile), 0..0)))
+#[derive(Clone, Copy, Debug)]
+enum Mode<'a> {
+    // Outside of `<?php ... ?>`
+    InlineHtml,
+    // Inside `{$...}` or `${...}`, counting the braces opened since
+    Interpolation(usize),
+    DoubleQuoted,
+    Backtick,
+    Heredoc(&'a str),
+}
+
+// Wraps the logos lexer to handle inline HTML and the contents of interpolated
+// strings, which depend on what has been lexed before.
+struct ModalLexer<'a> {
+    lexer: Lexer<'a, Token<'a>>,
+    modes: Vec<Mode<'a>>,
+    pending: VecDeque<(Result<'a>, Range<usize>)>,
+}
+
+impl<'a> ModalLexer<'a> {
+    fn new(content: &'a str, modes: Vec<Mode<'a>>) -> Self {
+        Self {
+            lexer: Token::lexer(content),
+            modes,
+            pending: VecDeque::new(),
+        }
+    }
+
+    // Skips the UTF-8 BOM and the shebang line at the start of a file
+    fn skip_preamble(&mut self) {
+        let content = self.lexer.source();
+        let bom = content.strip_prefix('\u{feff}').map_or(0, |_| 3);
+
+        let shebang = match content[bom..].starts_with("#!") {
+            true => content[bom..]
+                .find('\n')
+                .map_or(content.len() - bom, |end| end + 1),
+            false => 0,
+        };
+
+        self.lexer.bump(bom + shebang);
+    }
+
+    // Text up to the next `<?php` or `<?=`
+    fn inline_html_token(&mut self) {
+        let remainder = self.lexer.remainder();
+
+        if remainder.is_empty() {
+            return;
+        }
+
+        let mut pos = 0;
+        let tag = loop {
+            let Some(offset) = remainder[pos..].find("<?") else {
+                break None;
+            };
+            pos += offset;
+
+            let rest = &remainder[pos + 2..];
+            if rest.starts_with('=') {
+                break Some((Token::ScriptSectionStartWithEchoTag, 3));
+            }
+
+            // `<?php` must be followed by a whitespace, which is part of the tag
+            if rest
+                .get(..3)
+                .is_some_and(|php| php.eq_ignore_ascii_case("php"))
+            {
+                let whitespace = match rest.as_bytes()[3..] {
+                    [] => Some(0),
+                    [b'\r', b'\n', ..] => Some(2),
+                    [b' ' | b'\t' | b'\n' | b'\r', ..] => Some(1),
+                    _ => None,
+                };
+
+                if let Some(whitespace) = whitespace {
+                    break Some((Token::ScriptSectionStartTag, 5 + whitespace));
+                }
+            }
+
+            pos += 2;
+        };
+
+        let text = match tag {
+            Some(_) => &remainder[..pos],
+            None => remainder,
+        };
+
+        if !text.is_empty() {
+            self.push(Token::InlineHtml(text), text.len());
+        }
+
+        if let Some((token, len)) = tag {
+            self.modes.clear();
+            self.push(token, len);
+        }
+    }
+
+    // Emits a token made of the next `len` bytes
+    fn push(&mut self, token: Token<'a>, len: usize) {
+        let start = self.lexer.span().end;
+        self.lexer.bump(len);
+        self.pending.push_back((Ok(token), start..start + len));
+    }
+
+    fn script_token(&mut self) -> Option<(Result<'a>, Range<usize>)> {
+        let token = self.lexer.next()?;
+
+        match (&token, self.modes.last_mut()) {
+            (Ok(Token::ScriptSectionEndTag), _) => {
+                self.modes.clear();
+                self.modes.push(Mode::InlineHtml);
+            }
+            (Ok(Token::DoubleQuote), _) => self.modes.push(Mode::DoubleQuoted),
+            (Ok(Token::Backtick), _) => self.modes.push(Mode::Backtick),
+            (Ok(Token::HeredocStart), _) => {
+                let label = self.lexer.slice()[3..].trim().trim_matches('"');
+                self.modes.push(Mode::Heredoc(label));
+            }
+            (Ok(Token::OpenBrace | Token::DollarOpenBrace), Some(Mode::Interpolation(depth))) => {
+                *depth += 1
+            }
+            (Ok(Token::CloseBrace), Some(Mode::Interpolation(0))) => {
+                self.modes.pop();
+            }
+            (Ok(Token::CloseBrace), Some(Mode::Interpolation(depth))) => *depth -= 1,
+            _ => {}
+        }
+
+        Some((token, self.lexer.span()))
+    }
+
+    fn string_token(&mut self, mode: Mode<'a>) {
+        let remainder = self.lexer.remainder();
+
+        if remainder.is_empty() {
+            return;
+        }
+
+        let (heredoc_label, quote) = match mode {
+            Mode::Heredoc(label) => (Some(label), None),
+            Mode::Backtick => (None, Some(b'`')),
+            _ => (None, Some(b'"')),
+        };
+
+        match mode {
+            Mode::Heredoc(label) => {
+                let newline = match remainder.as_bytes() {
+                    [b'\n', ..] => 1,
+                    [b'\r', b'\n', ..] => 2,
+                    _ => 0,
+                };
+
+                if newline > 0 {
+                    if let Some(marker) = closing_marker(&remainder[newline..], label) {
+                        self.modes.pop();
+                        let len = newline + marker;
+                        return self.push(Token::HeredocEnd(&remainder[..len]), len);
+                    }
+                }
+            }
+            Mode::DoubleQuoted if remainder.starts_with('"') => {
+                self.modes.pop();
+                return self.push(Token::DoubleQuote, 1);
+            }
+            Mode::Backtick if remainder.starts_with('`') => {
+                self.modes.pop();
+                return self.push(Token::Backtick, 1);
+            }
+            _ => {}
+        }
+
+        if remainder.starts_with("{$") {
+            self.modes.push(Mode::Interpolation(0));
+            return self.push(Token::OpenBraceDollar, 1);
+        }
+
+        if let Some(rest) = remainder.strip_prefix("${") {
+            self.modes.push(Mode::Interpolation(0));
+            self.push(Token::DollarOpenBrace, 2);
+
+            let len = label_len(rest);
+            if len > 0 && rest[len..].starts_with('}') {
+                self.push(Token::StringVarname(&rest[..len]), len);
+            }
+            return;
+        }
+
+        if let Some(name) = remainder.strip_prefix('$') {
+            let len = label_len(name) + 1;
+            if len > 1 {
+                self.push(Token::VariableName(&remainder[..len]), len);
+                return self.variable_suffix();
+            }
+        }
+
+        let bytes = remainder.as_bytes();
+        let mut pos = 0;
+        while pos < bytes.len() {
+            match bytes[pos] {
+                b'\\' => pos += 2,
+                // The chunk is never empty: quotes and interpolations at its start are lexed above
+                _ if pos == 0 => pos += 1,
+                c if Some(c) == quote => break,
+                b'\n'
+                    if heredoc_label.is_some_and(|label| {
+                        closing_marker(&remainder[pos + 1..], label).is_some()
+                    }) =>
+                {
+                    // The newline before the closing marker is part of `HeredocEnd`
+                    if bytes[pos - 1] == b'\r' {
+                        pos -= 1;
+                    }
+                    break;
+                }
+                _ if starts_interpolation(&bytes[pos..]) => break,
+                _ => pos += 1,
+            }
+        }
+
+        let len = pos.min(bytes.len());
+        self.push(Token::EncapsedAndWhitespace(&remainder[..len]), len);
+    }
+
+    // `[offset]` or `->name` directly following a variable in a string
+    fn variable_suffix(&mut self) {
+        let remainder = self.lexer.remainder();
+
+        if let Some(rest) = remainder.strip_prefix("->") {
+            let len = label_len(rest);
+            if len > 0 {
+                self.push(Token::Arrow, 2);
+                self.push(Token::Name(&rest[..len]), len);
+            }
+            return;
+        }
+
+        let Some(rest) = remainder.strip_prefix('[') else {
+            return;
+        };
+
+        let name = label_len(rest);
+        let variable = rest.strip_prefix('$').map_or(0, label_len);
+        let negative = rest.starts_with('-') as usize;
+        let digits = rest[negative..]
+            .bytes()
+            .take_while(u8::is_ascii_digit)
+            .count();
+
+        let offset = if name > 0 {
+            vec![(Token::Name(&rest[..name]), name)]
+        } else if variable > 0 {
+            vec![(Token::VariableName(&rest[..variable + 1]), variable + 1)]
+        } else if digits > 0 {
+            let integer = &rest[negative..negative + digits];
+            match negative {
+                0 => vec![(Token::IntegerLiteral(integer), digits)],
+                _ => vec![(Token::Minus, 1), (Token::IntegerLiteral(integer), digits)],
+            }
+        } else {
+            return;
+        };
+
+        let len = offset.iter().map(|(_, len)| len).sum::<usize>();
+        if !rest[len..].starts_with(']') {
+            return;
+        }
+
+        self.push(Token::OpenBracket, 1);
+        for (token, len) in offset {
+            self.push(token, len);
+        }
+        self.push(Token::CloseBracket, 1);
+    }
+}
+
+impl<'a> Iterator for ModalLexer<'a> {
+    type Item = (Result<'a>, Range<usize>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.pending.is_empty() {
+            match self.modes.last().copied() {
+                Some(Mode::InlineHtml) => self.inline_html_token(),
+                Some(mode @ (Mode::DoubleQuoted | Mode::Backtick | Mode::Heredoc(_))) => {
+                    self.string_token(mode)
+                }
+                _ => return self.script_token(),
+            }
+        }
+
+        self.pending.pop_front()
+    }
+}
+
+pub fn lexer(content: &str) -> impl Iterator<Item = (Result<'_>, Range<usize>)> {
+    let mut lexer = ModalLexer::new(content, vec![Mode::InlineHtml]);
+    lexer.skip_preamble();
+
+    lexer.chain(Some((Ok(Token::EndOfFile), 0..0)))
+}
+
+// Lexes `content` as if it directly followed a `<?php` tag
+pub fn script_lexer(content: &str) -> impl Iterator<Item = (Result<'_>, Range<usize>)> {
+    ModalLexer::new(content, Vec::new()).chain(Some((Ok(Token::EndOfFile), 0..0)))
 }
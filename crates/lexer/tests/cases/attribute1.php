@@ -0,0 +1,4 @@
+<?php
+#[Pure]
+# comment
+function f() {}
\ No newline at end of file
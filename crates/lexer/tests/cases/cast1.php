@@ -0,0 +1,4 @@
+<?php
+(int)$a;
+( String )$b;
+(in_array)($c);
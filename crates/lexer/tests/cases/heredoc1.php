@@ -0,0 +1,7 @@
+<?php
+$a = <<<EOT
+    hello
+    EOT;
+$b = <<<'EOT'
+world
+EOT;
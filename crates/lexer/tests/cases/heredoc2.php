@@ -0,0 +1,4 @@
+<?php
+<<<EOT
+    Hi ${name}
+    EOT;
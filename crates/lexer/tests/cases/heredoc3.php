@@ -0,0 +1,4 @@
+<?php
+<<<EOT
+    $a\$b {$c}\\
+    EOT;
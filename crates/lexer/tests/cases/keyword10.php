@@ -0,0 +1,2 @@
+<?php
+InsteadOf
\ No newline at end of file
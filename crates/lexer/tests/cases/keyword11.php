@@ -0,0 +1,2 @@
+<?php
+public private(set) PROTECTED(set) public(set) private (set)
\ No newline at end of file
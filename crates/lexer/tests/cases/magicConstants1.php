@@ -0,0 +1,2 @@
+<?php
+__LINE__ __file__ __DIR__ __CLASS__ __FUNCTION__ __METHOD__ __NAMESPACE__ __TRAIT__
\ No newline at end of file
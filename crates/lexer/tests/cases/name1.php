@@ -0,0 +1,2 @@
+<?php
+Base64 md5 utf8_encode $ünïcödé _9
\ No newline at end of file
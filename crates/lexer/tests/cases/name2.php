@@ -0,0 +1,2 @@
+<?php
+$query->list()::DEFAULT
\ No newline at end of file
@@ -0,0 +1,2 @@
+<?php
+App\Foo \App\Foo Namespace\Foo \ namespace
\ No newline at end of file
@@ -0,0 +1,2 @@
+<?php
+0xFF_ec
\ No newline at end of file
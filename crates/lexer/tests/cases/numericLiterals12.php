@@ -0,0 +1,2 @@
+<?php
+0o17
\ No newline at end of file
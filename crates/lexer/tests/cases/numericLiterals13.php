@@ -0,0 +1,2 @@
+<?php
+1_000_000
\ No newline at end of file
@@ -0,0 +1,2 @@
+<?php
+1e10
\ No newline at end of file
@@ -0,0 +1,2 @@
+<?php
+1_0.5_0e1_0
\ No newline at end of file
@@ -0,0 +1,2 @@
+<?php
+0b1010_0101
\ No newline at end of file
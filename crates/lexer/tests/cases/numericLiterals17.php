@@ -0,0 +1,2 @@
+<?php
+1__0
\ No newline at end of file
@@ -0,0 +1 @@
+<p><?= $title ?></p>
@@ -0,0 +1 @@
+<?php `ls -la $dir {$opts}`;
\ No newline at end of file
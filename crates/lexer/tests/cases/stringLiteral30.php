@@ -0,0 +1,2 @@
+<?php
+"Hello {$user->name}, you have $count items";
@@ -0,0 +1,5 @@
+<?php
+"$a\"b";
+"{$a}\"";
+"$a\\";
+"$a\$b";
@@ -0,0 +1,112 @@
+use chumsky::prelude::*;
+use chumsky::{error::Rich, extra, input::ValueInput, span::SimpleSpan, Parser};
+
+use phprs_lexer::Token;
+
+use crate::parser::expressions::argument_expression::ArgumentExpression;
+use crate::parser::expressions::Expression;
+use crate::parser::BoxedParser;
+
+use super::name::qualified_name::QualifiedName;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Attribute<'a> {
+    pub name: QualifiedName<'a>,
+    pub arguments: Vec<ArgumentExpression<'a>>,
+}
+
+// #[First, Second('argument')]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct AttributeGroup<'a>(pub Vec<Attribute<'a>>);
+
+impl<'a> AttributeGroup<'a> {
+    pub fn parser<I>(
+        expression_parser: BoxedParser<'a, I, Expression<'a>>,
+    ) -> impl Parser<'a, I, Self, extra::Err<Rich<'a, Token<'a>>>> + Clone
+    where
+        I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
+    {
+        let arguments = just(Token::OpenParen)
+            .ignore_then(ArgumentExpression::list_parser(expression_parser))
+            .then_ignore(just(Token::CloseParen))
+            .or_not()
+            .map(|arguments| arguments.unwrap_or_default());
+
+        let attribute = QualifiedName::parser()
+            .then(arguments)
+            .map(|(name, arguments)| Attribute { name, arguments });
+
+        just(Token::Attribute)
+            .ignore_then(
+                attribute
+                    .separated_by(just(Token::Comma))
+                    .allow_trailing()
+                    .at_least(1)
+                    .collect(),
+            )
+            .then_ignore(just(Token::CloseBracket))
+            .map(Self)
+            .labelled("AttributeGroup")
+    }
+
+    pub fn list_parser<I>(
+        expression_parser: BoxedParser<'a, I, Expression<'a>>,
+    ) -> impl Parser<'a, I, Vec<Self>, extra::Err<Rich<'a, Token<'a>>>> + Clone
+    where
+        I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
+    {
+        Self::parser(expression_parser)
+            .repeated()
+            .collect::<Vec<_>>()
+            .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::{statements::Statement, tokenize};
+
+    use super::*;
+
+    fn parse(src: &str) -> Result<Vec<AttributeGroup<'_>>, ()> {
+        let tokens = tokenize(src);
+
+        AttributeGroup::list_parser(Expression::parser(Statement::parser().boxed()))
+            .parse(tokens)
+            .into_result()
+            .map_err(|_| ())
+    }
+
+    #[test]
+    fn simple() {
+        let res = parse(r#"#[Pure]"#).unwrap();
+        assert!(matches!(&res[..], [AttributeGroup(attributes)] if attributes.len() == 1));
+    }
+
+    #[test]
+    fn arguments() {
+        let res = parse(r#"#[Route('/users', methods: ['GET'])]"#).unwrap();
+        assert!(matches!(
+            &res[..],
+            [AttributeGroup(attributes)] if attributes[0].arguments.len() == 2
+        ));
+    }
+
+    #[test]
+    fn grouped() {
+        let res = parse(r#"#[Id, Column('integer'),]"#).unwrap();
+        assert!(matches!(&res[..], [AttributeGroup(attributes)] if attributes.len() == 2));
+    }
+
+    #[test]
+    fn multiple_groups() {
+        let res = parse("#[First]\n#[Second]").unwrap();
+        assert_eq!(res.len(), 2);
+    }
+
+    #[test]
+    fn empty_fail() {
+        let res = parse(r#"#[]"#);
+        assert_eq!(res, Err(()));
+    }
+}
@@ -0,0 +1,86 @@
+use chumsky::{input::ValueInput, span::SimpleSpan};
+use chumsky::{prelude::*, Parser};
+
+use phprs_lexer::Token;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CastType {
+    Array,
+    Bool,
+    Float,
+    Int,
+    Object,
+    String,
+    Unset,
+}
+
+impl<'a> CastType {
+    pub fn parser<I>() -> impl Parser<'a, I, Self, extra::Err<Rich<'a, Token<'a>>>> + Clone
+    where
+        I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
+    {
+        select! {
+            Token::ArrayCast => Self::Array,
+            Token::BoolCast => Self::Bool,
+            Token::FloatCast => Self::Float,
+            Token::IntCast => Self::Int,
+            Token::ObjectCast => Self::Object,
+            Token::StringCast => Self::String,
+            Token::UnsetCast => Self::Unset,
+        }
+        .labelled("CastType")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::tokenize;
+
+    use super::*;
+
+    fn parse(src: &str) -> Result<CastType, ()> {
+        let token_stream = tokenize(src);
+
+        CastType::parser()
+            .parse(token_stream)
+            .into_result()
+            .map_err(|_| ())
+    }
+
+    #[test]
+    fn int() {
+        assert_eq!(parse(r#"(int)"#), Ok(CastType::Int));
+        assert_eq!(parse(r#"(integer)"#), Ok(CastType::Int));
+    }
+
+    #[test]
+    fn case_insensitive() {
+        assert_eq!(parse(r#"(BOOL)"#), Ok(CastType::Bool));
+        assert_eq!(parse(r#"(Boolean)"#), Ok(CastType::Bool));
+    }
+
+    #[test]
+    fn whitespace() {
+        assert_eq!(parse(r#"( float )"#), Ok(CastType::Float));
+        assert_eq!(parse("(\tstring  )"), Ok(CastType::String));
+    }
+
+    #[test]
+    fn aliases() {
+        assert_eq!(parse(r#"(double)"#), Ok(CastType::Float));
+        assert_eq!(parse(r#"(real)"#), Ok(CastType::Float));
+        assert_eq!(parse(r#"(binary)"#), Ok(CastType::String));
+    }
+
+    #[test]
+    fn others() {
+        assert_eq!(parse(r#"(array)"#), Ok(CastType::Array));
+        assert_eq!(parse(r#"(object)"#), Ok(CastType::Object));
+        assert_eq!(parse(r#"(unset)"#), Ok(CastType::Unset));
+    }
+
+    #[test]
+    fn parenthesized_name_fail() {
+        assert_eq!(parse(r#"(foo)"#), Err(()));
+    }
+}
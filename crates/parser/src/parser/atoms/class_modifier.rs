@@ -7,6 +7,7 @@ use phprs_lexer::Token;
 pub enum ClassModifier {
     Abstract,
     Final,
+    Readonly,
 }
 
 impl<'a> ClassModifier {
@@ -15,8 +16,9 @@ impl<'a> ClassModifier {
         I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
     {
         select! {
-            Token::AbstractKeyword => Self::Abstract,
-            Token::FinalKeyword => Self::Final
+            Token::AbstractKeyword(_) => Self::Abstract,
+            Token::FinalKeyword(_) => Self::Final,
+            Token::ReadonlyKeyword(_) => Self::Readonly,
         }
     }
 }
@@ -47,4 +49,10 @@ mod tests {
         let res = parse(r#"final"#);
         assert_eq!(res, Ok(ClassModifier::Final));
     }
+
+    #[test]
+    fn readonly() {
+        let res = parse(r#"readonly"#);
+        assert_eq!(res, Ok(ClassModifier::Readonly));
+    }
 }
@@ -7,11 +7,14 @@ use chumsky::{prelude::*, Parser};
 use phprs_lexer::Token;
 
 use super::name::qualified_name::QualifiedName;
+use super::relative_scope::RelativeScope;
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub enum ClassTypeDesignator<'a> {
     QualifiedName(QualifiedName<'a>),
     NewVariable(NewVariable<'a>),
+    // `new static()` or `$value instanceof self`
+    RelativeScope(RelativeScope),
 }
 
 impl<'a> ClassTypeDesignator<'a> {
@@ -23,8 +26,10 @@ impl<'a> ClassTypeDesignator<'a> {
     {
         let qualified_name = QualifiedName::parser().map(Self::QualifiedName);
         let new_variable = NewVariable::parser(expression_parser).map(Self::NewVariable);
+        let relative_scope = RelativeScope::parser().map(Self::RelativeScope);
 
-        choice((new_variable, qualified_name))
+        // NOTE: After `new_variable` so `static::$class` is not cut short
+        choice((new_variable, relative_scope, qualified_name))
     }
 }
 
@@ -49,6 +54,27 @@ mod tests {
         assert!(matches!(res, Ok(ClassTypeDesignator::QualifiedName(_))))
     }
 
+    #[test]
+    fn relative_scope() {
+        assert_eq!(
+            parse(r#"self"#),
+            Ok(ClassTypeDesignator::RelativeScope(RelativeScope::Self_))
+        );
+        assert_eq!(
+            parse(r#"static"#),
+            Ok(ClassTypeDesignator::RelativeScope(RelativeScope::Static))
+        );
+        assert_eq!(
+            parse(r#"parent"#),
+            Ok(ClassTypeDesignator::RelativeScope(RelativeScope::Parent))
+        );
+
+        assert!(matches!(
+            parse(r#"static::$class"#),
+            Ok(ClassTypeDesignator::NewVariable(_))
+        ));
+    }
+
     #[test]
     fn new() {
         let res = parse(r#"$test"#);
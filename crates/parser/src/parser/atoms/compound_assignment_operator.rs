@@ -0,0 +1,95 @@
+use chumsky::{input::ValueInput, span::SimpleSpan};
+use chumsky::{prelude::*, Parser};
+
+use phprs_lexer::Token;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CompoundAssignmentOperator {
+    Power,
+    Multiply,
+    Divide,
+    Modulo,
+    Add,
+    Sub,
+    Concatenate,
+    LeftShift,
+    RightShift,
+    BitwiseAnd,
+    BitwiseExc,
+    BitwiseInc,
+    Coalesce,
+}
+
+impl<'a> CompoundAssignmentOperator {
+    pub fn parser<I>() -> impl Parser<'a, I, Self, extra::Err<Rich<'a, Token<'a>>>> + Clone
+    where
+        I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
+    {
+        select! {
+            Token::AsteriskAsteriskEquals => Self::Power,
+            Token::AsteriskEquals => Self::Multiply,
+            Token::SlashEquals => Self::Divide,
+            Token::PercentEquals => Self::Modulo,
+            Token::PlusEquals => Self::Add,
+            Token::MinusEquals => Self::Sub,
+            Token::DotEquals => Self::Concatenate,
+            Token::LessThanLessThanEquals => Self::LeftShift,
+            Token::GreaterThanGreaterThanEquals => Self::RightShift,
+            Token::AmpersandEquals => Self::BitwiseAnd,
+            Token::CaretEquals => Self::BitwiseExc,
+            Token::BarEquals => Self::BitwiseInc,
+            Token::QuestionQuestionEquals => Self::Coalesce,
+        }
+        .labelled("CompoundAssignmentOperator")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::tokenize;
+
+    use super::*;
+
+    fn parse(src: &str) -> Result<CompoundAssignmentOperator, ()> {
+        let token_stream = tokenize(src);
+
+        CompoundAssignmentOperator::parser()
+            .parse(token_stream)
+            .into_result()
+            .map_err(|_| ())
+    }
+
+    #[test]
+    fn arithmetic() {
+        assert_eq!(parse(r#"**="#), Ok(CompoundAssignmentOperator::Power));
+        assert_eq!(parse(r#"*="#), Ok(CompoundAssignmentOperator::Multiply));
+        assert_eq!(parse(r#"/="#), Ok(CompoundAssignmentOperator::Divide));
+        assert_eq!(parse(r#"%="#), Ok(CompoundAssignmentOperator::Modulo));
+        assert_eq!(parse(r#"+="#), Ok(CompoundAssignmentOperator::Add));
+        assert_eq!(parse(r#"-="#), Ok(CompoundAssignmentOperator::Sub));
+    }
+
+    #[test]
+    fn concatenate() {
+        assert_eq!(parse(r#".="#), Ok(CompoundAssignmentOperator::Concatenate));
+    }
+
+    #[test]
+    fn bitwise() {
+        assert_eq!(parse(r#"<<="#), Ok(CompoundAssignmentOperator::LeftShift));
+        assert_eq!(parse(r#">>="#), Ok(CompoundAssignmentOperator::RightShift));
+        assert_eq!(parse(r#"&="#), Ok(CompoundAssignmentOperator::BitwiseAnd));
+        assert_eq!(parse(r#"^="#), Ok(CompoundAssignmentOperator::BitwiseExc));
+        assert_eq!(parse(r#"|="#), Ok(CompoundAssignmentOperator::BitwiseInc));
+    }
+
+    #[test]
+    fn coalesce() {
+        assert_eq!(parse(r#"??="#), Ok(CompoundAssignmentOperator::Coalesce));
+    }
+
+    #[test]
+    fn simple_assignment_fail() {
+        assert_eq!(parse(r#"="#), Err(()));
+    }
+}
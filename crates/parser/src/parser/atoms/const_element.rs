@@ -4,7 +4,6 @@ use chumsky::{error::Rich, extra, input::ValueInput, span::SimpleSpan, Parser};
 use phprs_lexer::Token;
 
 use crate::parser::expressions::Expression;
-use crate::parser::statements::Statement;
 use crate::parser::BoxedParser;
 
 use super::name::Name;
@@ -17,25 +16,25 @@ pub struct ConstElement<'a> {
 
 impl<'a> ConstElement<'a> {
     pub fn parser<I>(
-        statement_parser: BoxedParser<'a, I, Statement<'a>>,
+        expression_parser: BoxedParser<'a, I, Expression<'a>>,
     ) -> impl Parser<'a, I, Self, extra::Err<Rich<'a, Token<'a>>>> + Clone
     where
         I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
     {
-        Name::parser()
+        Name::identifier_parser()
             .then_ignore(just(Token::Equals))
-            .then(Expression::parser(statement_parser))
+            .then(expression_parser)
             .map(|(name, expression)| ConstElement { name, expression })
             .labelled("ConstElement")
     }
 
     pub fn list_parser<I>(
-        statement_parser: BoxedParser<'a, I, Statement<'a>>,
+        expression_parser: BoxedParser<'a, I, Expression<'a>>,
     ) -> impl Parser<'a, I, Vec<Self>, extra::Err<Rich<'a, Token<'a>>>> + Clone
     where
         I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
     {
-        Self::parser(statement_parser)
+        Self::parser(expression_parser)
             .separated_by(just(Token::Comma))
             .collect()
     }
@@ -47,6 +46,7 @@ mod tests {
         expressions::{
             primary_expression::PrimaryExpression, reserved_word_expression::ReservedWordExpression,
         },
+        statements::Statement,
         tokenize,
     };
 
@@ -55,7 +55,7 @@ mod tests {
     fn parse(src: &str) -> Result<ConstElement, ()> {
         let token_stream = tokenize(src);
 
-        ConstElement::parser(Statement::parser())
+        ConstElement::parser(Expression::parser(Statement::parser()))
             .parse(token_stream)
             .into_result()
             .map_err(|_| ())
@@ -64,7 +64,7 @@ mod tests {
     fn parse_list(src: &str) -> Result<Vec<ConstElement>, ()> {
         let token_stream = tokenize(src);
 
-        ConstElement::list_parser(Statement::parser())
+        ConstElement::list_parser(Expression::parser(Statement::parser()))
             .parse(token_stream)
             .into_result()
             .map_err(|_| ())
@@ -2,6 +2,7 @@ pub mod empty_intrinsic;
 pub mod eval_intrinsic;
 pub mod exit_intrinsic;
 pub mod isset_intrinsic;
+pub mod list_intrinsic;
 
 use chumsky::prelude::*;
 use chumsky::{error::Rich, extra, input::ValueInput, span::SimpleSpan, Parser};
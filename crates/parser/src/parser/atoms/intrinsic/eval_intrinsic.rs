@@ -4,6 +4,7 @@ use chumsky::{error::Rich, extra, input::ValueInput, span::SimpleSpan, Parser};
 use phprs_lexer::Token;
 
 use crate::parser::expressions::Expression;
+use crate::parser::keyword;
 use crate::parser::BoxedParser;
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
@@ -16,7 +17,7 @@ impl<'a> EvalIntrinsic<'a> {
     where
         I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
     {
-        just(Token::EvalKeyword)
+        keyword(Token::EvalKeyword)
             .ignore_then(just(Token::OpenParen))
             .ignore_then(expression_parser)
             .then_ignore(just(Token::CloseParen))
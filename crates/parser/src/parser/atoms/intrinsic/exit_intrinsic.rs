@@ -4,6 +4,7 @@ use chumsky::{error::Rich, extra, input::ValueInput, span::SimpleSpan, Parser};
 use phprs_lexer::Token;
 
 use crate::parser::expressions::Expression;
+use crate::parser::keyword;
 use crate::parser::BoxedParser;
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
@@ -22,7 +23,7 @@ impl<'a> ExitIntrinsic<'a> {
             .or_not()
             .map(|t| t.unwrap_or_default());
 
-        choice((just(Token::ExitKeyword), just(Token::DieKeyword)))
+        choice((keyword(Token::ExitKeyword), keyword(Token::DieKeyword)))
             .ignore_then(body)
             .map(Self)
             .labelled("ExitIntrinsic")
@@ -4,6 +4,7 @@ use chumsky::{error::Rich, extra, input::ValueInput, span::SimpleSpan, Parser};
 use phprs_lexer::Token;
 
 use crate::parser::expressions::Expression;
+use crate::parser::keyword;
 use crate::parser::variables::Variable;
 use crate::parser::BoxedParser;
 
@@ -17,7 +18,7 @@ impl<'a> IssetIntrinsic<'a> {
     where
         I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
     {
-        just(Token::IssetKeyword)
+        keyword(Token::IssetKeyword)
             .ignore_then(just(Token::OpenParen))
             .ignore_then(Variable::list_parser(expression_parser))
             .then_ignore(just(Token::CloseParen))
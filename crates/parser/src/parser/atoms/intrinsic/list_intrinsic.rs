@@ -0,0 +1,285 @@
+use chumsky::prelude::*;
+use chumsky::{error::Rich, extra, input::ValueInput, span::SimpleSpan, Parser};
+
+use phprs_lexer::Token;
+
+use crate::parser::expressions::array_creation_expression::{
+    ArrayCreationExpression, ArrayElement,
+};
+use crate::parser::expressions::primary_expression::PrimaryExpression;
+use crate::parser::expressions::Expression;
+use crate::parser::keyword;
+use crate::parser::variables::Variable;
+use crate::parser::BoxedParser;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ListOrVariable<'a> {
+    Variable {
+        reference: bool,
+        variable: Variable<'a>,
+    },
+    List(ListIntrinsic<'a>),
+}
+
+impl<'a> ListOrVariable<'a> {
+    pub fn parser<I>(
+        expression_parser: BoxedParser<'a, I, Expression<'a>>,
+        list_instrinsic: BoxedParser<'a, I, ListIntrinsic<'a>>,
+    ) -> impl Parser<'a, I, Self, extra::Err<Rich<'a, Token<'a>>>> + Clone
+    where
+        I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
+    {
+        let variable = just(Token::Ampersand)
+            .or_not()
+            .map(|t| t.is_some())
+            .then(Variable::parser(expression_parser))
+            .map(|(reference, variable)| Self::Variable {
+                reference,
+                variable,
+            });
+
+        let list_instrinsic = list_instrinsic.map(Self::List);
+
+        variable.or(list_instrinsic)
+    }
+
+    fn from_element(element: &ArrayElement<'a>) -> Result<Self, &'static str> {
+        match (&element.value, element.reference) {
+            (Expression::Primary(PrimaryExpression::Variable(variable)), reference) => {
+                Ok(Self::Variable {
+                    reference,
+                    variable: *variable.clone(),
+                })
+            }
+            (Expression::Primary(PrimaryExpression::ArrayCreation(array)), false) => {
+                ListIntrinsic::from_array(array).map(Self::List)
+            }
+            _ => Err("Assignments can only happen to writable values"),
+        }
+    }
+}
+
+// `list($a, $b)` or `[$a, $b]`
+// NOTE: Only valid as an assignment target or a foreach value, not part of `Intrinsic`
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ListIntrinsic<'a> {
+    Keyed(Vec<(Expression<'a>, ListOrVariable<'a>)>),
+    // NOTE: Skipped elements (`[, $b]`) are `None`
+    Unkeyed(Vec<Option<ListOrVariable<'a>>>),
+}
+
+impl<'a> ListIntrinsic<'a> {
+    pub fn parser<I>(
+        expression_parser: BoxedParser<'a, I, Expression<'a>>,
+    ) -> impl Parser<'a, I, Self, extra::Err<Rich<'a, Token<'a>>>> + Clone
+    where
+        I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
+    {
+        let short = just(Token::OpenBracket)
+            .rewind()
+            .ignore_then(ArrayCreationExpression::parser(expression_parser.clone()))
+            .validate(|array, e, emitter| {
+                Self::from_array(&array).unwrap_or_else(|message| {
+                    emitter.emit(Rich::custom(e.span(), message));
+                    Self::Unkeyed(vec![])
+                })
+            });
+
+        choice((Self::keyword_parser(expression_parser), short)).labelled("ListIntrinsic")
+    }
+
+    // NOTE: `[$a, $b]` is an array creation until an `=` follows, only `list($a, $b)` can be
+    // parsed as a list upfront
+    pub fn keyword_parser<I>(
+        expression_parser: BoxedParser<'a, I, Expression<'a>>,
+    ) -> impl Parser<'a, I, Self, extra::Err<Rich<'a, Token<'a>>>> + Clone
+    where
+        I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
+    {
+        recursive(|list_intrinsic| {
+            let element = ListOrVariable::parser(expression_parser.clone(), list_intrinsic.boxed());
+
+            let keyed = expression_parser
+                .then_ignore(just(Token::DoubleArrow))
+                .then(element.clone())
+                .separated_by(just(Token::Comma))
+                .allow_trailing()
+                .at_least(1)
+                .collect()
+                .map(Self::Keyed);
+
+            let unkeyed = element
+                .or_not()
+                .separated_by(just(Token::Comma))
+                .collect::<Vec<_>>()
+                .validate(|elements, e, emitter| {
+                    if elements.iter().all(Option::is_none) {
+                        emitter.emit(Rich::custom(e.span(), "Cannot use empty list"));
+                    }
+
+                    elements
+                })
+                .map(Self::Unkeyed);
+
+            // NOTE: `unkeyed` first so nested lists are not parsed again as keys
+            let elements = unkeyed
+                .then_ignore(just(Token::CloseParen))
+                .or(keyed.then_ignore(just(Token::CloseParen)));
+
+            keyword(Token::ListKeyword)
+                .ignore_then(just(Token::OpenParen))
+                .ignore_then(elements)
+        })
+        .labelled("ListIntrinsic")
+        .boxed()
+    }
+
+    pub fn from_array(array: &ArrayCreationExpression<'a>) -> Result<Self, &'static str> {
+        if array.elements.iter().all(Option::is_none) {
+            return Err("Cannot use empty list");
+        }
+
+        let keyed = array
+            .elements
+            .iter()
+            .any(|element| matches!(element, Some(ArrayElement { key: Some(_), .. })));
+
+        if !keyed {
+            return array
+                .elements
+                .iter()
+                .map(|element| {
+                    element
+                        .as_ref()
+                        .map(ListOrVariable::from_element)
+                        .transpose()
+                })
+                .collect::<Result<_, _>>()
+                .map(Self::Unkeyed);
+        }
+
+        array
+            .elements
+            .iter()
+            .map(|element| match element {
+                Some(element @ ArrayElement { key: Some(key), .. }) => {
+                    Ok((key.clone(), ListOrVariable::from_element(element)?))
+                }
+                Some(_) => Err("Cannot mix keyed and unkeyed array entries in assignments"),
+                None => Err("Cannot use empty array entries in keyed array assignment"),
+            })
+            .collect::<Result<_, _>>()
+            .map(Self::Keyed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::{statements::Statement, tokenize};
+
+    use super::*;
+
+    fn parse(src: &str) -> Result<ListIntrinsic<'_>, ()> {
+        let tokens = tokenize(src);
+
+        ListIntrinsic::parser(Expression::parser(Statement::parser().boxed()))
+            .parse(tokens)
+            .into_result()
+            .map_err(|_| ())
+    }
+
+    #[test]
+    fn list() {
+        let res = parse(r#"list($a, $b)"#);
+        assert!(matches!(res, Ok(ListIntrinsic::Unkeyed(elements)) if elements.len() == 2));
+    }
+
+    #[test]
+    fn short() {
+        let res = parse(r#"[$a, $b]"#);
+        assert!(matches!(res, Ok(ListIntrinsic::Unkeyed(elements)) if elements.len() == 2));
+    }
+
+    #[test]
+    fn keyed() {
+        let res = parse(r#"['id' => $id, 'name' => $name]"#);
+        assert!(matches!(res, Ok(ListIntrinsic::Keyed(elements)) if elements.len() == 2));
+    }
+
+    #[test]
+    fn skipped() {
+        let res = parse(r#"[, $b]"#);
+        assert!(matches!(
+            res,
+            Ok(ListIntrinsic::Unkeyed(elements)) if matches!(elements[..], [None, Some(_)])
+        ));
+    }
+
+    #[test]
+    fn nested() {
+        let res = parse(r#"[[$a, $b], [$c]]"#);
+        assert!(matches!(
+            res,
+            Ok(ListIntrinsic::Unkeyed(elements)) if matches!(
+                elements[..],
+                [Some(ListOrVariable::List(_)), Some(ListOrVariable::List(_))]
+            )
+        ));
+    }
+
+    #[test]
+    fn reference() {
+        let res = parse(r#"['a' => &$a, 'b' => $b]"#);
+        assert!(matches!(
+            res,
+            Ok(ListIntrinsic::Keyed(elements))
+                if matches!(elements[0].1, ListOrVariable::Variable { reference: true, .. })
+        ));
+    }
+
+    #[test]
+    fn empty_fail() {
+        let res = parse(r#"list()"#);
+        assert_eq!(res, Err(()));
+
+        let res = parse(r#"[,]"#);
+        assert_eq!(res, Err(()));
+    }
+
+    #[test]
+    fn nested_keyword() {
+        let res = parse(r#"list(list($a, $b), $c)"#);
+        assert!(matches!(
+            res,
+            Ok(ListIntrinsic::Unkeyed(elements))
+                if matches!(elements[..], [Some(ListOrVariable::List(_)), Some(_)])
+        ));
+    }
+
+    #[test]
+    fn nested_deep() {
+        let src = format!("{}$a{}", "list(".repeat(32), ")".repeat(32));
+        assert!(matches!(parse(&src), Ok(ListIntrinsic::Unkeyed(_))));
+
+        let src = format!("{}$a{}", "list($k => ".repeat(32), ")".repeat(32));
+        assert!(matches!(parse(&src), Ok(ListIntrinsic::Keyed(_))));
+    }
+
+    #[test]
+    fn mixed_keys_fail() {
+        let res = parse(r#"['a' => $a, $b]"#);
+        assert_eq!(res, Err(()));
+
+        let res = parse(r#"['a' => $a, , 'b' => $b]"#);
+        assert_eq!(res, Err(()));
+    }
+
+    #[test]
+    fn not_writable_fail() {
+        let res = parse(r#"[$a, 1]"#);
+        assert_eq!(res, Err(()));
+
+        let res = parse(r#"[&[$a]]"#);
+        assert_eq!(res, Err(()));
+    }
+}
@@ -21,6 +21,31 @@ pub enum Literal<'a> {
     Binary(BinaryLiteral<'a>),
 }
 
+// Value of a numeric literal, integers overflowing `i64` are floats in PHP
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Number {
+    Integer(i64),
+    Float(f64),
+}
+
+impl Number {
+    // `digits` without the prefix, `_` separators are ignored
+    fn from_digits(digits: &str, radix: u32) -> Self {
+        let digits = digits.replace('_', "");
+
+        match i64::from_str_radix(&digits, radix) {
+            Ok(value) => Self::Integer(value),
+            Err(_) if radix == 10 => Self::Float(digits.parse().unwrap_or_default()),
+            Err(_) => Self::Float(
+                digits
+                    .chars()
+                    .filter_map(|c| c.to_digit(radix))
+                    .fold(0.0, |value, digit| value * radix as f64 + digit as f64),
+            ),
+        }
+    }
+}
+
 impl<'a> Literal<'a> {
     pub fn parser<I>() -> impl Parser<'a, I, Self, extra::Err<Rich<'a, Token<'a>>>> + Clone
     where
@@ -53,7 +78,7 @@ mod tests {
     #[test]
     fn string() {
         let res = parse(r#"'hello'"#);
-        assert_eq!(res, Ok(Literal::String(StringLiteral("'hello'"))));
+        assert_eq!(res, Ok(Literal::String(StringLiteral::new("'hello'"))));
     }
 
     #[test]
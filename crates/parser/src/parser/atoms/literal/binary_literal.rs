@@ -3,6 +3,8 @@ use chumsky::{error::Rich, extra, input::ValueInput, Parser};
 
 use phprs_lexer::Token;
 
+use super::Number;
+
 // TODO: Separate in multiple struct
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct BinaryLiteral<'a>(pub &'a str);
@@ -13,7 +15,58 @@ impl<'a> BinaryLiteral<'a> {
         I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
     {
         select! {
-            Token::BinaryLiteral(string) => Self(string),
+            Token::BinaryLiteral(string) => (Self(string), true),
+            Token::InvalidBinaryLiteral(string) => (Self(string), false),
         }
+        .validate(|(literal, valid), e, emitter| {
+            if !valid {
+                emitter.emit(Rich::custom(e.span(), "Invalid numeric literal"));
+            }
+
+            literal
+        })
+    }
+
+    pub fn value(&self) -> Number {
+        Number::from_digits(&self.0[2..], 2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::tokenize;
+
+    use super::*;
+
+    fn parse(src: &str) -> Result<BinaryLiteral<'_>, ()> {
+        let tokens = tokenize(src);
+
+        BinaryLiteral::parser()
+            .parse(tokens)
+            .into_result()
+            .map_err(|_| ())
+    }
+
+    #[test]
+    fn value() {
+        assert_eq!(
+            parse("0b1010_0101").unwrap().value(),
+            Number::Integer(0b1010_0101)
+        );
+        assert_eq!(parse("0B11").unwrap().value(), Number::Integer(3));
+    }
+
+    #[test]
+    fn overflow() {
+        let literal = format!("0b{}", "1".repeat(64));
+        assert_eq!(
+            parse(&literal).unwrap().value(),
+            Number::Float(u64::MAX as f64)
+        );
+    }
+
+    #[test]
+    fn invalid_fail() {
+        assert_eq!(parse("0b102"), Err(()));
     }
 }
@@ -16,4 +16,39 @@ impl<'a> FloatingLiteral<'a> {
             Token::FloatingLiteral(string) => Self(string),
         }
     }
+
+    pub fn value(&self) -> f64 {
+        self.0.replace('_', "").parse().unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::tokenize;
+
+    use super::*;
+
+    fn parse(src: &str) -> Result<FloatingLiteral<'_>, ()> {
+        let tokens = tokenize(src);
+
+        FloatingLiteral::parser()
+            .parse(tokens)
+            .into_result()
+            .map_err(|_| ())
+    }
+
+    #[test]
+    fn value() {
+        assert_eq!(parse("1.5").unwrap().value(), 1.5);
+        assert_eq!(parse(".5").unwrap().value(), 0.5);
+        assert_eq!(parse("1983.").unwrap().value(), 1983.0);
+        assert_eq!(parse("1_000.000_5").unwrap().value(), 1000.0005);
+    }
+
+    #[test]
+    fn exponent() {
+        assert_eq!(parse("1e10").unwrap().value(), 1e10);
+        assert_eq!(parse("1983.e-19").unwrap().value(), 1983e-19);
+        assert_eq!(parse("2.5E+3").unwrap().value(), 2500.0);
+    }
 }
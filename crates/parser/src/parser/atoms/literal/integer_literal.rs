@@ -3,6 +3,8 @@ use chumsky::{error::Rich, extra, input::ValueInput, Parser};
 
 use phprs_lexer::Token;
 
+use super::Number;
+
 // TODO: Separate in multiple struct
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct IntegerLiteral<'a>(pub &'a str);
@@ -15,5 +17,91 @@ impl<'a> IntegerLiteral<'a> {
         select! {
             Token::IntegerLiteral(string) => Self(string),
         }
+        .validate(|literal, e, emitter| {
+            // NOTE: Octals like `019` are lexed as decimals
+            let (digits, radix) = literal.digits();
+            if !digits.chars().all(|c| c == '_' || c.is_digit(radix)) {
+                emitter.emit(Rich::custom(e.span(), "Invalid numeric literal"));
+            }
+
+            literal
+        })
+    }
+
+    pub fn value(&self) -> Number {
+        let (digits, radix) = self.digits();
+        Number::from_digits(digits, radix)
+    }
+
+    // Digits without the prefix and their radix
+    fn digits(&self) -> (&'a str, u32) {
+        let literal = self.0;
+
+        match literal.get(..2) {
+            Some("0x" | "0X") => (&literal[2..], 16),
+            Some("0o" | "0O") => (&literal[2..], 8),
+            Some(_) if literal.starts_with('0') => (&literal[1..], 8),
+            _ => (literal, 10),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::tokenize;
+
+    use super::*;
+
+    fn parse(src: &str) -> Result<IntegerLiteral<'_>, ()> {
+        let tokens = tokenize(src);
+
+        IntegerLiteral::parser()
+            .parse(tokens)
+            .into_result()
+            .map_err(|_| ())
+    }
+
+    #[test]
+    fn decimal() {
+        assert_eq!(parse("0").unwrap().value(), Number::Integer(0));
+        assert_eq!(
+            parse("1_000_000").unwrap().value(),
+            Number::Integer(1_000_000)
+        );
+    }
+
+    #[test]
+    fn hexadecimal() {
+        assert_eq!(parse("0xFF").unwrap().value(), Number::Integer(255));
+        assert_eq!(parse("0X1A_2b").unwrap().value(), Number::Integer(0x1a2b));
+    }
+
+    #[test]
+    fn octal() {
+        assert_eq!(parse("017").unwrap().value(), Number::Integer(15));
+        assert_eq!(parse("0o17").unwrap().value(), Number::Integer(15));
+        assert_eq!(parse("0O1_7").unwrap().value(), Number::Integer(15));
+        assert_eq!(parse("00").unwrap().value(), Number::Integer(0));
+    }
+
+    #[test]
+    fn overflow() {
+        assert_eq!(
+            parse("9223372036854775807").unwrap().value(),
+            Number::Integer(i64::MAX)
+        );
+        assert_eq!(
+            parse("9223372036854775808").unwrap().value(),
+            Number::Float(2f64.powi(63))
+        );
+        assert_eq!(
+            parse("0xFFFFFFFFFFFFFFFF").unwrap().value(),
+            Number::Float(u64::MAX as f64)
+        );
+    }
+
+    #[test]
+    fn invalid_octal_fail() {
+        assert_eq!(parse("019"), Err(()));
     }
 }
@@ -1,19 +1,154 @@
+use std::borrow::Cow;
+
 use chumsky::prelude::*;
 use chumsky::{error::Rich, extra, input::ValueInput, Parser};
 
 use phprs_lexer::Token;
 
-// TODO: Separate in multiple struct
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
-pub struct StringLiteral<'a>(pub &'a str);
+pub enum StringLiteralKind {
+    SingleQuoted,
+    DoubleQuoted,
+    Heredoc,
+    Nowdoc,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct StringLiteral<'a> {
+    pub kind: StringLiteralKind,
+    // Text as written in the source, including quotes or heredoc markers
+    pub raw: &'a str,
+}
 
 impl<'a> StringLiteral<'a> {
+    pub fn new(raw: &'a str) -> Self {
+        let unprefixed = raw.strip_prefix(['b', 'B']).unwrap_or(raw);
+
+        let kind = if let Some(heredoc) = raw.strip_prefix("<<<") {
+            match heredoc.trim_start_matches([' ', '\t']).starts_with('\'') {
+                true => StringLiteralKind::Nowdoc,
+                false => StringLiteralKind::Heredoc,
+            }
+        } else if unprefixed.starts_with('\'') {
+            StringLiteralKind::SingleQuoted
+        } else {
+            StringLiteralKind::DoubleQuoted
+        };
+
+        Self { kind, raw }
+    }
+
+    // Content of the literal without its delimiters. For heredoc and nowdoc the
+    // indentation of the closing marker is removed from every line.
+    // NOTE: Escape sequences are kept as written
+    pub fn value(&self) -> Cow<'a, str> {
+        match self.kind {
+            StringLiteralKind::SingleQuoted | StringLiteralKind::DoubleQuoted => {
+                let unprefixed = self.raw.strip_prefix(['b', 'B']).unwrap_or(self.raw);
+                Cow::Borrowed(&unprefixed[1..unprefixed.len() - 1])
+            }
+            StringLiteralKind::Heredoc | StringLiteralKind::Nowdoc => {
+                let Some((_, content)) = self.raw.split_once('\n') else {
+                    return Cow::Borrowed("");
+                };
+                let Some((body, closing)) = content.rsplit_once('\n') else {
+                    return Cow::Borrowed("");
+                };
+                let body = body.strip_suffix('\r').unwrap_or(body);
+
+                let indentation = closing.len() - closing.trim_start_matches([' ', '\t']).len();
+                if indentation == 0 {
+                    return Cow::Borrowed(body);
+                }
+
+                let lines = body
+                    .split('\n')
+                    .map(|line| line.get(indentation..).unwrap_or(""))
+                    .collect::<Vec<_>>();
+
+                Cow::Owned(lines.join("\n"))
+            }
+        }
+    }
+
     pub fn parser<I>() -> impl Parser<'a, I, Self, extra::Err<Rich<'a, Token<'a>>>> + Clone
     where
         I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
     {
         select! {
-            Token::StringLiteral(string) => Self(string),
+            Token::StringLiteral(string) => Self::new(string),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::tokenize;
+
+    use super::*;
+
+    fn parse(src: &str) -> Result<StringLiteral<'_>, ()> {
+        let tokens = tokenize(src);
+
+        StringLiteral::parser()
+            .parse(tokens)
+            .into_result()
+            .map_err(|_| ())
+    }
+
+    #[test]
+    fn single_quoted() {
+        let res = parse(r#"'hello'"#).unwrap();
+        assert_eq!(res.kind, StringLiteralKind::SingleQuoted);
+        assert_eq!(res.value(), "hello");
+    }
+
+    #[test]
+    fn double_quoted() {
+        let res = parse(r#"b"hello""#).unwrap();
+        assert_eq!(res.kind, StringLiteralKind::DoubleQuoted);
+        assert_eq!(res.value(), "hello");
+    }
+
+    #[test]
+    fn heredoc() {
+        let res = parse("<<<EOT\nhello\n  world\nEOT").unwrap();
+        assert_eq!(res.kind, StringLiteralKind::Heredoc);
+        assert_eq!(res.value(), "hello\n  world");
+
+        let res = parse("<<<\"EOT\"\nhello\nEOT").unwrap();
+        assert_eq!(res.kind, StringLiteralKind::Heredoc);
+        assert_eq!(res.value(), "hello");
+    }
+
+    #[test]
+    fn nowdoc() {
+        let res = parse("<<<'EOT'\nhello $name\nEOT").unwrap();
+        assert_eq!(res.kind, StringLiteralKind::Nowdoc);
+        assert_eq!(res.value(), "hello $name");
+    }
+
+    #[test]
+    fn empty_heredoc() {
+        let res = parse("<<<EOT\nEOT").unwrap();
+        assert_eq!(res.value(), "");
+    }
+
+    #[test]
+    fn flexible_closing_marker() {
+        let res = parse("<<<SQL\n    SELECT *\n      FROM users\n\n    SQL").unwrap();
+        assert_eq!(res.value(), "SELECT *\n  FROM users\n");
+    }
+
+    #[test]
+    fn invalid_body_indentation_fail() {
+        let res = parse("<<<SQL\n    SELECT *\n  FROM users\n    SQL");
+        assert_eq!(res, Err(()));
+    }
+
+    #[test]
+    fn unterminated_heredoc_fail() {
+        let res = parse("<<<EOT\nhello\n");
+        assert_eq!(res, Err(()));
+    }
+}
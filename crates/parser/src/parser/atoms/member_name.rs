@@ -22,9 +22,10 @@ impl<'a> MemberName<'a> {
         I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
     {
         choice((
-            Name::parser().map(Self::Name),
+            Name::identifier_parser().map(Self::Name),
             SimpleVariable::parser(expression_parser.clone()).map(Self::SimpleVariable),
             expression_parser.map(Self::Expression),
         ))
+        .boxed()
     }
 }
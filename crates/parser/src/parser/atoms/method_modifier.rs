@@ -22,7 +22,9 @@ impl<'a> MethodModifier {
         choice((
             VisibilityModifier::parser().map(Self::Visibility),
             StaticModifier::parser().map(Self::Static),
-            ClassModifier::parser().map(Self::Class),
+            ClassModifier::parser()
+                .filter(|modifier| *modifier != ClassModifier::Readonly)
+                .map(Self::Class),
         ))
     }
 
@@ -1,6 +1,9 @@
+pub mod attribute;
 pub mod breakout_level;
+pub mod cast_type;
 pub mod class_modifier;
 pub mod class_type_designator;
+pub mod compound_assignment_operator;
 pub mod const_element;
 pub mod intrinsic;
 pub mod literal;
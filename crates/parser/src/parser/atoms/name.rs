@@ -20,6 +20,19 @@ impl<'a> Name<'a> {
         }
         .map(Name)
     }
+
+    // NOTE: Keywords are accepted as member, constant and enum case names
+    pub fn identifier_parser<I>(
+    ) -> impl Parser<'a, I, Self, extra::Err<Rich<'a, Token<'a>>>> + Clone
+    where
+        I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
+    {
+        select! {
+            Token::Name(name) => Name(name),
+            token if token.keyword().is_some() => Name(token.keyword().unwrap_or_default()),
+        }
+        .labelled("Identifier")
+    }
 }
 
 #[cfg(test)]
@@ -48,4 +61,29 @@ mod tests {
         let res = parse(r#"hello_WoRLD"#);
         assert_eq!(res, Ok(Name("hello_WoRLD")));
     }
+
+    #[test]
+    fn keyword_fail() {
+        let res = parse(r#"list"#);
+        assert_eq!(res, Err(()));
+    }
+
+    #[test]
+    fn identifier() {
+        let parse = |src| {
+            Name::identifier_parser()
+                .parse(tokenize(src))
+                .into_result()
+                .map_err(|_| ())
+        };
+
+        assert_eq!(parse(r#"count"#), Ok(Name("count")));
+        assert_eq!(parse(r#"list"#), Ok(Name("list")));
+        assert_eq!(parse(r#"DEFAULT"#), Ok(Name("DEFAULT")));
+        assert_eq!(parse(r#"Class"#), Ok(Name("Class")));
+        assert_eq!(parse(r#"True"#), Ok(Name("True")));
+        assert_eq!(parse(r#"__construct"#), Ok(Name("__construct")));
+        assert_eq!(parse(r#"$list"#), Err(()));
+        assert_eq!(parse(r#";"#), Err(()));
+    }
 }
@@ -11,15 +11,11 @@ impl<'a> NamespaceName<'a> {
     where
         I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
     {
-        let name = select! {
-            Token::Name(name) => name
-        };
-
-        name.separated_by(just(Token::Backslack))
-            .at_least(1)
-            .collect::<Vec<_>>()
-            .map(NamespaceName)
-            .labelled("Namespace name")
+        select! {
+            Token::Name(name) => NamespaceName(vec![name]),
+            Token::QualifiedName(name) => NamespaceName(name.split('\\').collect()),
+        }
+        .labelled("Namespace name")
     }
 }
 
@@ -3,69 +3,102 @@ use chumsky::{error::Rich, extra, input::ValueInput, span::SimpleSpan, Parser};
 
 use phprs_lexer::Token;
 
-use super::namespace_name::NamespaceName;
-use super::Name;
-
-// TODO: Check what is `namespace \App\`
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
-pub struct NamespaceNameAsPrefix<'a> {
-    namespace: Option<NamespaceName<'a>>,
-    global: bool,
-}
-
-impl<'a> NamespaceNameAsPrefix<'a> {
-    pub fn parser<I>() -> impl Parser<'a, I, Self, extra::Err<Rich<'a, Token<'a>>>> + Clone
-    where
-        I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
-    {
-        // `\`
-        let empty = just(Token::Backslack).map(|_| Self {
-            global: true,
-            namespace: None,
-        });
-
-        let simple = just(Token::Backslack)
-            .or_not()
-            .map(|t| t.is_some())
-            .then(NamespaceName::parser())
-            .then_ignore(just(Token::Backslack))
-            .map(|(global, namespace)| Self {
-                global,
-                namespace: Some(namespace),
-            });
-
-        let with_keyword = just(Token::NamespaceKeyword)
-            .ignore_then(
-                just(Token::Backslack)
-                    .ignore_then(NamespaceName::parser())
-                    .or_not(),
-            )
-            .then_ignore(just(Token::Backslack))
-            .map(|namespace| Self {
-                global: true,
-                namespace,
-            });
-
-        empty.or(simple).or(with_keyword)
-    }
+pub enum QualifiedNameKind {
+    // `Foo`
+    Unqualified,
+    // `App\Foo`
+    Qualified,
+    // `\App\Foo`
+    FullyQualified,
+    // `namespace\Foo`
+    Relative,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct QualifiedName<'a> {
-    namespace: Option<NamespaceNameAsPrefix<'a>>,
-    name: Name<'a>,
+    pub kind: QualifiedNameKind,
+    // Without the leading `\` or `namespace\`
+    pub parts: Vec<&'a str>,
 }
 
-// TODO: Should be a Token
-// Test\Hey does not work
 impl<'a> QualifiedName<'a> {
+    pub fn new(kind: QualifiedNameKind, name: &'a str) -> Self {
+        let name = match kind {
+            QualifiedNameKind::FullyQualified => &name[1..],
+            QualifiedNameKind::Relative => &name["namespace\\".len()..],
+            _ => name,
+        };
+
+        Self {
+            kind,
+            parts: name.split('\\').collect(),
+        }
+    }
+
     pub fn parser<I>() -> impl Parser<'a, I, Self, extra::Err<Rich<'a, Token<'a>>>> + Clone
     where
         I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
     {
-        NamespaceNameAsPrefix::parser()
-            .or_not()
-            .then(Name::parser())
-            .map(|(namespace, name)| Self { namespace, name })
+        select! {
+            Token::Name(name) => Self::new(QualifiedNameKind::Unqualified, name),
+            Token::QualifiedName(name) => Self::new(QualifiedNameKind::Qualified, name),
+            Token::FullyQualifiedName(name) => Self::new(QualifiedNameKind::FullyQualified, name),
+            Token::RelativeName(name) => Self::new(QualifiedNameKind::Relative, name),
+        }
+        .labelled("QualifiedName")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::tokenize;
+
+    use super::*;
+
+    fn parse(src: &str) -> Result<QualifiedName<'_>, ()> {
+        let token_stream = tokenize(src);
+
+        QualifiedName::parser()
+            .parse(token_stream)
+            .into_result()
+            .map_err(|_| ())
+    }
+
+    #[test]
+    fn unqualified() {
+        let res = parse(r#"Foo"#).unwrap();
+        assert_eq!(res.kind, QualifiedNameKind::Unqualified);
+        assert_eq!(res.parts, vec!["Foo"]);
+    }
+
+    #[test]
+    fn qualified() {
+        let res = parse(r#"Test\Hey"#).unwrap();
+        assert_eq!(res.kind, QualifiedNameKind::Qualified);
+        assert_eq!(res.parts, vec!["Test", "Hey"]);
+    }
+
+    #[test]
+    fn fully_qualified() {
+        let res = parse(r#"\App\Models\User"#).unwrap();
+        assert_eq!(res.kind, QualifiedNameKind::FullyQualified);
+        assert_eq!(res.parts, vec!["App", "Models", "User"]);
+
+        let res = parse(r#"\strlen"#).unwrap();
+        assert_eq!(res.kind, QualifiedNameKind::FullyQualified);
+        assert_eq!(res.parts, vec!["strlen"]);
+    }
+
+    #[test]
+    fn relative() {
+        let res = parse(r#"namespace\Sub\foo"#).unwrap();
+        assert_eq!(res.kind, QualifiedNameKind::Relative);
+        assert_eq!(res.parts, vec!["Sub", "foo"]);
+    }
+
+    #[test]
+    fn whitespace_fail() {
+        assert_eq!(parse(r#"App \ Foo"#), Err(()));
     }
 }
@@ -3,19 +3,26 @@ use chumsky::{error::Rich, extra, input::ValueInput, span::SimpleSpan, Parser};
 
 use phprs_lexer::Token;
 
+use crate::parser::atoms::attribute::AttributeGroup;
 use crate::parser::atoms::name::variable_name::VariableName;
 use crate::parser::atoms::r#type::type_declaration::TypeDeclaration;
 use crate::parser::atoms::visibility_modifier::VisibilityModifier;
+use crate::parser::class::property_hook::PropertyHook;
 use crate::parser::expressions::Expression;
+use crate::parser::keyword;
+use crate::parser::statements::Statement;
 use crate::parser::BoxedParser;
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct ParameterDeclaration<'a> {
+    attributes: Vec<AttributeGroup<'a>>,
     r#type: Option<TypeDeclaration<'a>>,
     reference: bool,
     name: VariableName<'a>,
     default: Option<Expression<'a>>,
     visibility: Option<VisibilityModifier>,
+    readonly: bool,
+    hooks: Vec<PropertyHook<'a>>,
 }
 
 impl<'a> ParameterDeclaration<'a> {
@@ -25,26 +32,67 @@ impl<'a> ParameterDeclaration<'a> {
     where
         I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
     {
-        let visibility = VisibilityModifier::parser().or_not();
+        let attributes = AttributeGroup::list_parser(expression_parser.clone());
+        // NOTE: Constructor property promotion, `readonly` may come before or after the visibility
+        let modifiers = choice((
+            keyword(Token::ReadonlyKeyword)
+                .ignore_then(VisibilityModifier::parser().or_not())
+                .map(|visibility| (visibility, true)),
+            VisibilityModifier::parser().or_not().then(
+                keyword(Token::ReadonlyKeyword)
+                    .or_not()
+                    .map(|t| t.is_some()),
+            ),
+        ));
         let type_declaration = TypeDeclaration::parser().or_not();
         let reference = just(Token::Ampersand).or_not().map(|t| t.is_some());
         let variable_name = VariableName::parser();
         let default = just(Token::Equals).ignore_then(expression_parser).or_not();
 
-        visibility
+        attributes
+            .then(modifiers)
             .then(type_declaration)
             .then(reference)
             .then(variable_name)
             .then(default)
             .map(
-                |((((visibility, r#type), reference), name), default)| ParameterDeclaration {
-                    visibility,
-                    r#type,
-                    reference,
-                    name,
-                    default,
+                |(((((attributes, (visibility, readonly)), r#type), reference), name), default)| {
+                    ParameterDeclaration {
+                        attributes,
+                        visibility,
+                        readonly,
+                        r#type,
+                        reference,
+                        name,
+                        default,
+                        hooks: vec![],
+                    }
                 },
             )
+            .boxed()
+    }
+
+    // NOTE: Constructor parameters, only promoted ones can have hooks
+    pub fn promoted_parser<I>(
+        statement_parser: BoxedParser<'a, I, Statement<'a>>,
+        expression_parser: BoxedParser<'a, I, Expression<'a>>,
+    ) -> impl Parser<'a, I, Self, extra::Err<Rich<'a, Token<'a>>>> + Clone
+    where
+        I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
+    {
+        Self::parser(expression_parser.clone())
+            .then(PropertyHook::list_parser(statement_parser, expression_parser).or_not())
+            .validate(|(parameter, hooks), e, emitter| {
+                let hooks = hooks.unwrap_or_default();
+                if !hooks.is_empty() && parameter.visibility.is_none() && !parameter.readonly {
+                    emitter.emit(Rich::custom(
+                        e.span(),
+                        "Cannot declare hooks for a parameter that is not promoted",
+                    ));
+                }
+
+                Self { hooks, ..parameter }
+            })
     }
 
     pub fn list_parser<I>(
@@ -67,7 +115,6 @@ mod tests {
         expressions::{
             primary_expression::PrimaryExpression, reserved_word_expression::ReservedWordExpression,
         },
-        statements::Statement,
         tokenize,
     };
 
@@ -88,11 +135,14 @@ mod tests {
         assert_eq!(
             res,
             Ok(ParameterDeclaration {
+                attributes: vec![],
                 visibility: Some(VisibilityModifier::Public),
+                readonly: false,
                 r#type: None,
                 reference: false,
                 name: VariableName("$test"),
                 default: None,
+                hooks: vec![],
             })
         );
     }
@@ -103,11 +153,14 @@ mod tests {
         assert_eq!(
             res,
             Ok(ParameterDeclaration {
+                attributes: vec![],
                 visibility: None,
+                readonly: false,
                 r#type: None,
                 reference: false,
                 name: VariableName("$test"),
                 default: None,
+                hooks: vec![],
             })
         );
     }
@@ -118,11 +171,14 @@ mod tests {
         assert_eq!(
             res,
             Ok(ParameterDeclaration {
+                attributes: vec![],
                 visibility: None,
+                readonly: false,
                 r#type: None,
                 reference: true,
                 name: VariableName("$test"),
                 default: None,
+                hooks: vec![],
             })
         );
     }
@@ -133,14 +189,16 @@ mod tests {
         assert_eq!(
             res,
             Ok(ParameterDeclaration {
+                attributes: vec![],
                 visibility: None,
+                readonly: false,
                 r#type: Some(TypeDeclaration {
-                    optional: false,
                     r#type: Type::Array
                 }),
                 reference: false,
                 name: VariableName("$test"),
                 default: None,
+                hooks: vec![],
             })
         );
     }
@@ -151,14 +209,38 @@ mod tests {
         assert_eq!(
             res,
             Ok(ParameterDeclaration {
+                attributes: vec![],
                 visibility: None,
+                readonly: false,
                 r#type: None,
                 reference: false,
                 name: VariableName("$test"),
                 default: Some(Expression::Primary(PrimaryExpression::ReservedWord(
                     ReservedWordExpression::True
                 ))),
+                hooks: vec![],
             })
         );
     }
+
+    #[test]
+    fn attributes() {
+        let res = parse(r#"#[SensitiveParameter] string $password"#).unwrap();
+        assert_eq!(res.attributes.len(), 1);
+    }
+
+    #[test]
+    fn promoted_readonly() {
+        let res = parse(r#"public readonly int $id"#).unwrap();
+        assert_eq!(res.visibility, Some(VisibilityModifier::Public));
+        assert!(res.readonly);
+
+        let res = parse(r#"readonly private string $name"#).unwrap();
+        assert_eq!(res.visibility, Some(VisibilityModifier::Private));
+        assert!(res.readonly);
+
+        let res = parse(r#"readonly $value"#).unwrap();
+        assert_eq!(res.visibility, None);
+        assert!(res.readonly);
+    }
 }
@@ -2,46 +2,57 @@ use chumsky::{input::ValueInput, prelude::*};
 use phprs_lexer::Token;
 
 use crate::parser::atoms::{
-    name::variable_name::VariableName, r#type::type_declaration::TypeDeclaration,
+    attribute::AttributeGroup, name::variable_name::VariableName,
+    r#type::type_declaration::TypeDeclaration,
 };
+use crate::parser::expressions::Expression;
+use crate::parser::BoxedParser;
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct VariadicParameter<'a> {
+    attributes: Vec<AttributeGroup<'a>>,
     r#type: Option<TypeDeclaration<'a>>,
     reference: bool,
     name: VariableName<'a>,
 }
 
 impl<'a> VariadicParameter<'a> {
-    pub fn parser<I>() -> impl Parser<'a, I, Self, extra::Err<Rich<'a, Token<'a>>>> + Clone
+    pub fn parser<I>(
+        expression_parser: BoxedParser<'a, I, Expression<'a>>,
+    ) -> impl Parser<'a, I, Self, extra::Err<Rich<'a, Token<'a>>>> + Clone
     where
         I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
     {
+        let attributes = AttributeGroup::list_parser(expression_parser);
         let type_declaration = TypeDeclaration::parser().or_not();
         let reference = just(Token::Ampersand).or_not().map(|t| t.is_some());
 
-        type_declaration
+        attributes
+            .then(type_declaration)
             .then(reference)
             .then_ignore(just(Token::DotDotDot))
             .then(VariableName::parser())
-            .map(|((r#type, reference), name)| VariadicParameter {
-                r#type,
-                reference,
-                name,
-            })
+            .map(
+                |(((attributes, r#type), reference), name)| VariadicParameter {
+                    attributes,
+                    r#type,
+                    reference,
+                    name,
+                },
+            )
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::parser::{atoms::r#type::Type, tokenize};
+    use crate::parser::{atoms::r#type::Type, statements::Statement, tokenize};
 
     use super::*;
 
     fn parse(src: &str) -> Result<VariadicParameter, ()> {
         let token_stream = tokenize(src);
 
-        VariadicParameter::parser()
+        VariadicParameter::parser(Expression::parser(Statement::parser()))
             .parse(token_stream)
             .into_result()
             .map_err(|_| ())
@@ -53,6 +64,7 @@ mod tests {
         assert_eq!(
             res,
             Ok(VariadicParameter {
+                attributes: vec![],
                 r#type: None,
                 reference: false,
                 name: VariableName("$test")
@@ -66,6 +78,7 @@ mod tests {
         assert_eq!(
             res,
             Ok(VariadicParameter {
+                attributes: vec![],
                 r#type: None,
                 reference: true,
                 name: VariableName("$test"),
@@ -79,8 +92,8 @@ mod tests {
         assert_eq!(
             res,
             Ok(VariadicParameter {
+                attributes: vec![],
                 r#type: Some(TypeDeclaration {
-                    optional: false,
                     r#type: Type::Array
                 }),
                 reference: false,
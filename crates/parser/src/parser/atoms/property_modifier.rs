@@ -3,6 +3,7 @@ use chumsky::{prelude::*, Parser};
 
 use phprs_lexer::Token;
 
+use super::class_modifier::ClassModifier;
 use super::static_modifier::StaticModifier;
 use super::visibility_modifier::VisibilityModifier;
 
@@ -10,31 +11,134 @@ use super::visibility_modifier::VisibilityModifier;
 pub enum PropertyModifier {
     Var,
     Visibility(VisibilityModifier),
+    // `private(set)`
+    SetVisibility(VisibilityModifier),
     Static(StaticModifier),
-    VisibilityAndStatic(VisibilityModifier, StaticModifier),
+    Readonly,
+    // `abstract` or `final` hooked properties
+    Class(ClassModifier),
 }
 
 impl<'a> PropertyModifier {
-    // TODO: Might be a better way to parse that
     pub fn parser<I>() -> impl Parser<'a, I, Self, extra::Err<Rich<'a, Token<'a>>>> + Clone
     where
         I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
     {
-        let var = select! { Token::VarKeyword => Self::Var };
-
-        let visiblity_first = VisibilityModifier::parser()
-            .then(StaticModifier::parser().or_not())
-            .map(|(visibility, r#static)| match r#static {
-                Some(r#static) => Self::VisibilityAndStatic(visibility, r#static),
-                None => Self::Visibility(visibility),
-            });
-        let static_first = StaticModifier::parser()
-            .then(VisibilityModifier::parser().or_not())
-            .map(|(r#static, visibility)| match visibility {
-                Some(visibility) => Self::VisibilityAndStatic(visibility, r#static),
-                None => Self::Static(r#static),
-            });
-
-        choice((var, visiblity_first, static_first))
+        let keywords = select! {
+            Token::VarKeyword(_) => Self::Var,
+            Token::ReadonlyKeyword(_) => Self::Readonly,
+        };
+
+        choice((
+            keywords,
+            VisibilityModifier::parser().map(Self::Visibility),
+            VisibilityModifier::set_parser().map(Self::SetVisibility),
+            StaticModifier::parser().map(Self::Static),
+            ClassModifier::parser()
+                .filter(|modifier| *modifier != ClassModifier::Readonly)
+                .map(Self::Class),
+        ))
+    }
+
+    // NOTE: Duplicated or conflicting modifiers are not reported
+    pub fn list_parser<I>() -> impl Parser<'a, I, Vec<Self>, extra::Err<Rich<'a, Token<'a>>>> + Clone
+    where
+        I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
+    {
+        Self::parser().repeated().at_least(1).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::tokenize;
+
+    use super::*;
+
+    fn parse(src: &str) -> Result<Vec<PropertyModifier>, ()> {
+        let tokens = tokenize(src);
+
+        PropertyModifier::list_parser()
+            .parse(tokens)
+            .into_result()
+            .map_err(|_| ())
+    }
+
+    #[test]
+    fn var() {
+        let res = parse(r#"var"#);
+        assert_eq!(res, Ok(vec![PropertyModifier::Var]));
+    }
+
+    #[test]
+    fn visibility_and_static() {
+        let res = parse(r#"static private"#);
+        assert_eq!(
+            res,
+            Ok(vec![
+                PropertyModifier::Static(StaticModifier),
+                PropertyModifier::Visibility(VisibilityModifier::Private),
+            ])
+        );
+    }
+
+    #[test]
+    fn readonly() {
+        let res = parse(r#"public readonly"#);
+        assert_eq!(
+            res,
+            Ok(vec![
+                PropertyModifier::Visibility(VisibilityModifier::Public),
+                PropertyModifier::Readonly,
+            ])
+        );
+    }
+
+    #[test]
+    fn asymmetric_visibility() {
+        let res = parse(r#"public private(set)"#);
+        assert_eq!(
+            res,
+            Ok(vec![
+                PropertyModifier::Visibility(VisibilityModifier::Public),
+                PropertyModifier::SetVisibility(VisibilityModifier::Private),
+            ])
+        );
+
+        let res = parse(r#"protected(set) readonly"#);
+        assert_eq!(
+            res,
+            Ok(vec![
+                PropertyModifier::SetVisibility(VisibilityModifier::Protected),
+                PropertyModifier::Readonly,
+            ])
+        );
+    }
+
+    #[test]
+    fn abstract_and_final() {
+        let res = parse(r#"abstract public"#);
+        assert_eq!(
+            res,
+            Ok(vec![
+                PropertyModifier::Class(ClassModifier::Abstract),
+                PropertyModifier::Visibility(VisibilityModifier::Public),
+            ])
+        );
+
+        let res = parse(r#"final protected"#);
+        assert_eq!(
+            res,
+            Ok(vec![
+                PropertyModifier::Class(ClassModifier::Final),
+                PropertyModifier::Visibility(VisibilityModifier::Protected),
+            ])
+        );
+    }
+
+    #[test]
+    fn empty_fail() {
+        let res = parse(r#""#);
+        assert_eq!(res, Err(()));
     }
 }
@@ -19,7 +19,7 @@ impl<'a> RelativeScope {
         select! {
             Token::SelfKeyword => Self::Self_,
             Token::ParentKeyworkd => Self::Parent,
-            Token::StaticKeyword => Self::Static,
+            Token::StaticKeyword(_) => Self::Static,
         }
     }
 }
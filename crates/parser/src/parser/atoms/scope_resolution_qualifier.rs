@@ -3,6 +3,8 @@ use chumsky::{prelude::*, Parser};
 
 use phprs_lexer::Token;
 
+use crate::parser::expressions::dereferencable_expression::DereferencableExpression;
+
 use super::name::qualified_name::QualifiedName;
 use super::relative_scope::RelativeScope;
 
@@ -10,7 +12,8 @@ use super::relative_scope::RelativeScope;
 pub enum ScopeResolutionQualifier<'a> {
     RelativeScope(RelativeScope),
     QualifiedName(QualifiedName<'a>),
-    DereferencableExpression, // TODO
+    // NOTE: `$object::CONSTANT`, only built as a postfix by `Variable`
+    DereferencableExpression(Box<DereferencableExpression<'a>>),
 }
 
 impl<'a> ScopeResolutionQualifier<'a> {
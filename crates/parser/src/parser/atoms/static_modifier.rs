@@ -12,7 +12,7 @@ impl<'a> StaticModifier {
         I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
     {
         select! {
-            Token::StaticKeyword => Self,
+            Token::StaticKeyword(_) => Self,
         }
     }
 }
@@ -17,25 +17,235 @@ pub enum Type<'a> {
     Callable,
     Iterable,
     Mixed,
+    Object,
+    Null,
+    False,
+    True,
+    Static,
+    Self_,
+    Parent,
     ScalarType(ScalarType),
     QualifiedName(QualifiedName<'a>),
+    // ?int
+    Nullable(Box<Type<'a>>),
+    // int|string, (A&B)|null
+    Union(Vec<Type<'a>>),
+    // Countable&Traversable
+    Intersection(Vec<Type<'a>>),
 }
 
 impl<'a> Type<'a> {
+    // NOTE: `void` and `never` are only valid as standalone return types, see `ReturnType`
     pub fn parser<I>() -> impl Parser<'a, I, Self, extra::Err<Rich<'a, Token<'a>>>> + Clone
     where
         I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
     {
         let keywords = select! {
-            Token::ArrayKeyword => Type::Array,
+            Token::ArrayKeyword(_) => Type::Array,
             Token::MixedReservedWord => Type::Mixed,
-            Token::CallableKeyword => Type::Callable,
+            Token::CallableKeyword(_) => Type::Callable,
             Token::IterableReservedWord => Type::Iterable,
+            Token::ObjectReservedWord => Type::Object,
+            Token::NullReservedWord => Type::Null,
+            Token::FalseReservedWord(_) => Type::False,
+            Token::TrueReservedWord(_) => Type::True,
+            Token::StaticKeyword(_) => Type::Static,
+            Token::SelfKeyword => Type::Self_,
+            Token::ParentKeyworkd => Type::Parent,
         };
 
         let qualified = QualifiedName::parser().map(Self::QualifiedName);
         let scalar = ScalarType::parser().map(Self::ScalarType);
 
-        choice((keywords, scalar, qualified))
+        let single = choice((keywords, scalar, qualified)).boxed();
+
+        let nullable =
+            just(Token::Question)
+                .ignore_then(single.clone())
+                .validate(|r#type, e, emitter| {
+                    if r#type == Type::Null {
+                        emitter.emit(Rich::custom(
+                            e.span(),
+                            "Type null cannot be marked as nullable",
+                        ));
+                    }
+                    if r#type == Type::Mixed {
+                        emitter.emit(Rich::custom(
+                            e.span(),
+                            "Type mixed cannot be marked as nullable",
+                        ));
+                    }
+
+                    Self::Nullable(Box::new(r#type))
+                });
+
+        // NOTE: `A & $b` is a by-reference parameter, the separator is rewound when no type follows
+        let intersection = single
+            .clone()
+            .separated_by(just(Token::Ampersand))
+            .at_least(2)
+            .collect::<Vec<_>>()
+            .validate(|types, e, emitter| {
+                if types.iter().any(|t| !matches!(t, Type::QualifiedName(_))) {
+                    emitter.emit(Rich::custom(
+                        e.span(),
+                        "Intersection types can only contain class types",
+                    ));
+                }
+                if Self::has_duplicate(&types) {
+                    emitter.emit(Rich::custom(e.span(), "Duplicate type is redundant"));
+                }
+
+                Self::Intersection(types)
+            })
+            .boxed();
+
+        let union = choice((
+            intersection
+                .clone()
+                .delimited_by(just(Token::OpenParen), just(Token::CloseParen)),
+            single.clone(),
+        ))
+        .separated_by(just(Token::Bar))
+        .at_least(2)
+        .collect::<Vec<_>>()
+        .validate(|types, e, emitter| {
+            if types.contains(&Type::Mixed) {
+                emitter.emit(Rich::custom(
+                    e.span(),
+                    "Type mixed can only be used as a standalone type",
+                ));
+            }
+            if Self::has_duplicate(&types) {
+                emitter.emit(Rich::custom(e.span(), "Duplicate type is redundant"));
+            }
+
+            Self::Union(types)
+        });
+
+        choice((nullable, union, intersection, single))
+            .labelled("Type")
+            .boxed()
+    }
+
+    fn has_duplicate(types: &[Self]) -> bool {
+        types
+            .iter()
+            .enumerate()
+            .any(|(i, r#type)| types[i + 1..].contains(r#type))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::tokenize;
+
+    use super::*;
+
+    fn parse(src: &str) -> Result<Type<'_>, ()> {
+        let token_stream = tokenize(src);
+
+        Type::parser()
+            .parse(token_stream)
+            .into_result()
+            .map_err(|_| ())
+    }
+
+    #[test]
+    fn standalone() {
+        assert_eq!(parse(r#"null"#), Ok(Type::Null));
+        assert_eq!(parse(r#"false"#), Ok(Type::False));
+        assert_eq!(parse(r#"true"#), Ok(Type::True));
+        assert_eq!(parse(r#"static"#), Ok(Type::Static));
+        assert_eq!(parse(r#"self"#), Ok(Type::Self_));
+    }
+
+    #[test]
+    fn nullable() {
+        let res = parse(r#"?int"#);
+        assert_eq!(
+            res,
+            Ok(Type::Nullable(Box::new(Type::ScalarType(ScalarType::Int))))
+        );
+    }
+
+    #[test]
+    fn union() {
+        let res = parse(r#"int|string|null"#);
+        assert_eq!(
+            res,
+            Ok(Type::Union(vec![
+                Type::ScalarType(ScalarType::Int),
+                Type::ScalarType(ScalarType::String),
+                Type::Null,
+            ]))
+        );
+    }
+
+    #[test]
+    fn intersection() {
+        let res = parse(r#"Countable&Traversable"#);
+        assert!(matches!(res, Ok(Type::Intersection(types)) if types.len() == 2));
+    }
+
+    #[test]
+    fn disjunctive_normal_form() {
+        let res = parse(r#"(A&B)|null"#);
+        assert!(matches!(
+            res,
+            Ok(Type::Union(types)) if matches!(&types[..], [Type::Intersection(_), Type::Null])
+        ));
+    }
+
+    #[test]
+    fn nullable_null_or_mixed_fail() {
+        assert_eq!(parse(r#"?null"#), Err(()));
+        assert_eq!(parse(r#"?mixed"#), Err(()));
+    }
+
+    #[test]
+    fn nullable_union_fail() {
+        let res = parse(r#"?int|string"#);
+        assert_eq!(res, Err(()));
+    }
+
+    #[test]
+    fn nullable_intersection_fail() {
+        let res = parse(r#"?A&B"#);
+        assert_eq!(res, Err(()));
+    }
+
+    #[test]
+    fn intersection_without_parens_in_union_fail() {
+        let res = parse(r#"A&B|null"#);
+        assert_eq!(res, Err(()));
+    }
+
+    #[test]
+    fn duplicate_fail() {
+        assert_eq!(parse(r#"int|int"#), Err(()));
+        assert_eq!(parse(r#"Foo|string|Foo"#), Err(()));
+        assert_eq!(parse(r#"A&B&A"#), Err(()));
+        assert_eq!(parse(r#"(A&B)|(A&B)"#), Err(()));
+    }
+
+    #[test]
+    fn mixed_union_fail() {
+        assert_eq!(parse(r#"mixed|int"#), Err(()));
+        assert_eq!(parse(r#"string|mixed"#), Err(()));
+    }
+
+    #[test]
+    fn non_class_intersection_fail() {
+        assert_eq!(parse(r#"int&string"#), Err(()));
+        assert_eq!(parse(r#"Countable&null"#), Err(()));
+        assert_eq!(parse(r#"(A&array)|null"#), Err(()));
+        assert_eq!(parse(r#"self&Countable"#), Err(()));
+    }
+
+    #[test]
+    fn void_fail() {
+        let res = parse(r#"void"#);
+        assert_eq!(res, Err(()));
     }
 }
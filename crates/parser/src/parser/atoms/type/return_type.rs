@@ -8,6 +8,7 @@ use super::type_declaration::TypeDeclaration;
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub enum ReturnType<'a> {
     Void,
+    Never,
     Declaration(TypeDeclaration<'a>),
 }
 
@@ -16,10 +17,13 @@ impl<'a> ReturnType<'a> {
     where
         I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
     {
-        let void = just(Token::VoidReservedWord).map(|_| ReturnType::Void);
+        let standalone = select! {
+            Token::VoidReservedWord => ReturnType::Void,
+            Token::NeverReservedWord => ReturnType::Never,
+        };
         let declaration = TypeDeclaration::parser().map(ReturnType::Declaration);
 
-        just(Token::Colon).ignore_then(void.or(declaration))
+        just(Token::Colon).ignore_then(standalone.or(declaration))
     }
 }
 
@@ -44,15 +48,43 @@ mod tests {
         assert_eq!(res, Ok(ReturnType::Void));
     }
 
+    #[test]
+    fn never() {
+        let res = parse(r#": never"#);
+        assert_eq!(res, Ok(ReturnType::Never));
+    }
+
     #[test]
     fn optional_type_declaration() {
         let res = parse(r#": ?array"#);
         assert_eq!(
             res,
             Ok(ReturnType::Declaration(TypeDeclaration {
-                optional: true,
-                r#type: Type::Array
+                r#type: Type::Nullable(Box::new(Type::Array))
             }))
         );
     }
+
+    #[test]
+    fn r#static() {
+        let res = parse(r#": static"#);
+        assert_eq!(
+            res,
+            Ok(ReturnType::Declaration(TypeDeclaration {
+                r#type: Type::Static
+            }))
+        );
+    }
+
+    #[test]
+    fn nullable_void_fail() {
+        let res = parse(r#": ?void"#);
+        assert_eq!(res, Err(()));
+    }
+
+    #[test]
+    fn void_union_fail() {
+        let res = parse(r#": void|null"#);
+        assert_eq!(res, Err(()));
+    }
 }
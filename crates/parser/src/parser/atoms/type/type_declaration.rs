@@ -1,4 +1,3 @@
-use chumsky::prelude::*;
 use chumsky::{error::Rich, extra, input::ValueInput, span::SimpleSpan, Parser};
 
 use phprs_lexer::Token;
@@ -7,7 +6,6 @@ use super::Type;
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct TypeDeclaration<'a> {
-    pub optional: bool,
     pub r#type: Type<'a>,
 }
 
@@ -16,11 +14,7 @@ impl<'a> TypeDeclaration<'a> {
     where
         I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
     {
-        let optional = just(Token::Question).or_not().map(|t| t.is_some());
-
-        optional
-            .then(Type::parser())
-            .map(|(optional, r#type)| TypeDeclaration { r#type, optional })
+        Type::parser().map(|r#type| TypeDeclaration { r#type })
     }
 }
 
@@ -45,7 +39,6 @@ mod tests {
         assert_eq!(
             res,
             Ok(TypeDeclaration {
-                optional: false,
                 r#type: Type::Array
             })
         );
@@ -57,8 +50,7 @@ mod tests {
         assert_eq!(
             res,
             Ok(TypeDeclaration {
-                optional: true,
-                r#type: Type::Callable
+                r#type: Type::Nullable(Box::new(Type::Callable))
             })
         );
     }
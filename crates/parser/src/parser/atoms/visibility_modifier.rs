@@ -16,9 +16,21 @@ impl<'a> VisibilityModifier {
         I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
     {
         select! {
-            Token::PublicKeyword => Self::Public,
-            Token::ProtectedKeyword => Self::Protected,
-            Token::PrivateKeyword => Self::Private,
+            Token::PublicKeyword(_) => Self::Public,
+            Token::ProtectedKeyword(_) => Self::Protected,
+            Token::PrivateKeyword(_) => Self::Private,
+        }
+    }
+
+    // `private(set)`, the visibility for writing a property
+    pub fn set_parser<I>() -> impl Parser<'a, I, Self, extra::Err<Rich<'a, Token<'a>>>> + Clone
+    where
+        I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
+    {
+        select! {
+            Token::PublicSetKeyword => Self::Public,
+            Token::ProtectedSetKeyword => Self::Protected,
+            Token::PrivateSetKeyword => Self::Private,
         }
     }
 }
@@ -55,4 +67,13 @@ mod tests {
         let res = parse(r#"private"#);
         assert_eq!(res, Ok(VisibilityModifier::Private));
     }
+
+    #[test]
+    fn set() {
+        let res = VisibilityModifier::set_parser()
+            .parse(tokenize(r#"protected(set)"#))
+            .into_result()
+            .map_err(|_| ());
+        assert_eq!(res, Ok(VisibilityModifier::Protected));
+    }
 }
@@ -1,34 +1,91 @@
 use chumsky::{input::ValueInput, span::SimpleSpan};
 use chumsky::{prelude::*, Parser};
 
+use crate::parser::atoms::attribute::AttributeGroup;
 use crate::parser::atoms::const_element::ConstElement;
+use crate::parser::atoms::name::Name;
+use crate::parser::atoms::r#type::type_declaration::TypeDeclaration;
 use crate::parser::atoms::visibility_modifier::VisibilityModifier;
-use crate::parser::statements::Statement;
+use crate::parser::expressions::Expression;
+use crate::parser::keyword;
 use crate::parser::BoxedParser;
 use phprs_lexer::Token;
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct ClassConstDeclaration<'a> {
+    attributes: Vec<AttributeGroup<'a>>,
     visibility: Option<VisibilityModifier>,
+    r#type: Option<TypeDeclaration<'a>>,
     elements: Vec<ConstElement<'a>>,
 }
 
 impl<'a> ClassConstDeclaration<'a> {
     pub fn parser<I>(
-        statement_parser: BoxedParser<'a, I, Statement<'a>>,
+        expression_parser: BoxedParser<'a, I, Expression<'a>>,
     ) -> impl Parser<'a, I, Self, extra::Err<Rich<'a, Token<'a>>>> + Clone
     where
         I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
     {
+        let attributes = AttributeGroup::list_parser(expression_parser.clone());
         let visiblity = VisibilityModifier::parser().or_not();
+        // NOTE: In `const FOO = 1` the name must not be taken as a type
+        let r#type = TypeDeclaration::parser()
+            .then_ignore(Name::identifier_parser().rewind())
+            .or_not();
 
-        visiblity
-            .then_ignore(just(Token::ConstKeyword))
-            .then(ConstElement::list_parser(statement_parser))
+        attributes
+            .then(visiblity)
+            .then_ignore(keyword(Token::ConstKeyword))
+            .then(r#type)
+            .then(ConstElement::list_parser(expression_parser))
             .then_ignore(just(Token::Semicolon))
-            .map(|(visibility, elements)| Self {
+            .map(|(((attributes, visibility), r#type), elements)| Self {
+                attributes,
                 visibility,
+                r#type,
                 elements,
             })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::atoms::r#type::scalar_type::ScalarType;
+    use crate::parser::atoms::r#type::Type;
+    use crate::parser::statements::Statement;
+    use crate::parser::tokenize;
+
+    use super::*;
+
+    fn parse(src: &str) -> Result<ClassConstDeclaration<'_>, ()> {
+        let tokens = tokenize(src);
+
+        ClassConstDeclaration::parser(Expression::parser(Statement::parser()))
+            .parse(tokens)
+            .into_result()
+            .map_err(|_| ())
+    }
+
+    #[test]
+    fn untyped() {
+        let res = parse(r#"public const FOO = 1, BAR = 2;"#);
+        assert!(matches!(
+            res,
+            Ok(ClassConstDeclaration { r#type: None, elements, .. }) if elements.len() == 2
+        ));
+    }
+
+    #[test]
+    fn typed() {
+        let res = parse(r#"const string FOO = 'foo';"#);
+        assert!(matches!(
+            res,
+            Ok(ClassConstDeclaration {
+                r#type: Some(TypeDeclaration {
+                    r#type: Type::ScalarType(ScalarType::String)
+                }),
+                ..
+            })
+        ));
+    }
+}
@@ -3,6 +3,7 @@ use chumsky::{prelude::*, Parser};
 
 use phprs_lexer::Token;
 
+use crate::parser::expressions::Expression;
 use crate::parser::statements::Statement;
 use crate::parser::BoxedParser;
 
@@ -26,28 +27,34 @@ pub enum ClassMemberDeclaration<'a> {
 impl<'a> ClassMemberDeclaration<'a> {
     pub fn parser<I>(
         statement_parser: BoxedParser<'a, I, Statement<'a>>,
+        expression_parser: BoxedParser<'a, I, Expression<'a>>,
     ) -> impl Parser<'a, I, Self, extra::Err<Rich<'a, Token<'a>>>> + Clone
     where
         I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
     {
-        let class_const_declaration = ClassConstDeclaration::parser(statement_parser.clone())
+        let class_const_declaration = ClassConstDeclaration::parser(expression_parser.clone())
             .map(Self::ClassConstDeclaration);
         let property_declaration =
-            PropertyDeclaration::parser(statement_parser.clone()).map(Self::PropertyDeclaration);
+            PropertyDeclaration::parser(statement_parser.clone(), expression_parser.clone())
+                .map(Self::PropertyDeclaration);
         let method_declaration =
-            MethodDeclaration::parser(statement_parser.clone()).map(Self::MethodDeclaration);
-        let constructor_declaration = ConstructorDeclaration::parser(statement_parser.clone())
-            .map(Self::ConstructorDeclaration);
+            MethodDeclaration::parser(statement_parser.clone(), expression_parser.clone())
+                .map(Self::MethodDeclaration);
+        let constructor_declaration =
+            ConstructorDeclaration::parser(statement_parser.clone(), expression_parser.clone())
+                .map(Self::ConstructorDeclaration);
         let destructor_declaration =
-            DestructorDeclaration::parser(statement_parser).map(Self::DestructorDeclaration);
+            DestructorDeclaration::parser(statement_parser, expression_parser)
+                .map(Self::DestructorDeclaration);
         let trait_use_clause = TraitUseClause::parser().map(Self::TraitUseClause);
 
         choice((
             class_const_declaration,
             property_declaration,
-            method_declaration,
+            // NOTE: Before `method_declaration` which accepts `__construct` as a name
             constructor_declaration,
             destructor_declaration,
+            method_declaration,
             trait_use_clause,
         ))
         .labelled("ClassMemberDeclaration")
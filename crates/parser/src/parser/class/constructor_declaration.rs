@@ -1,9 +1,12 @@
 use chumsky::{input::ValueInput, span::SimpleSpan};
 use chumsky::{prelude::*, Parser};
 
+use crate::parser::atoms::attribute::AttributeGroup;
 use crate::parser::atoms::method_modifier::MethodModifier;
 use crate::parser::atoms::parameter::parameter_declaration::ParameterDeclaration;
 use crate::parser::atoms::parameter::variadic_parameter::VariadicParameter;
+use crate::parser::expressions::Expression;
+use crate::parser::keyword;
 use crate::parser::statements::compound_statement::CompoundStatement;
 use crate::parser::statements::function_definition::FunctionDefinition;
 use crate::parser::statements::Statement;
@@ -12,6 +15,7 @@ use phprs_lexer::Token;
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct ConstructorDeclaration<'a> {
+    attributes: Vec<AttributeGroup<'a>>,
     modifiers: Vec<MethodModifier>,
     reference: bool,
     parameters: Vec<ParameterDeclaration<'a>>,
@@ -22,21 +26,25 @@ pub struct ConstructorDeclaration<'a> {
 impl<'a> ConstructorDeclaration<'a> {
     pub fn parser<I>(
         statement_parser: BoxedParser<'a, I, Statement<'a>>,
+        expression_parser: BoxedParser<'a, I, Expression<'a>>,
     ) -> BoxedParser<'a, I, Self>
     where
         I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
     {
+        let attributes = AttributeGroup::list_parser(expression_parser.clone());
         let modifiers = MethodModifier::list_parser();
         let reference = just(Token::Ampersand).or_not().map(|n| n.is_some());
 
-        let header = modifiers
-            .then_ignore(just(Token::FunctionKeyword))
+        let header = attributes
+            .then(modifiers)
+            .then_ignore(keyword(Token::FunctionKeyword))
             .then(reference)
             .then_ignore(just(Token::ConstructKeyword))
             .then(
                 just(Token::OpenParen)
-                    .ignore_then(FunctionDefinition::parameters_parser(
+                    .ignore_then(FunctionDefinition::promoted_parameters_parser(
                         statement_parser.clone(),
+                        expression_parser,
                     ))
                     .then_ignore(just(Token::CloseParen)),
             );
@@ -49,7 +57,8 @@ impl<'a> ConstructorDeclaration<'a> {
         header
             .then(body)
             .map(
-                |(((modifiers, reference), (parameters, variadic)), body)| Self {
+                |((((attributes, modifiers), reference), (parameters, variadic)), body)| Self {
+                    attributes,
                     modifiers,
                     reference,
                     parameters,
@@ -71,10 +80,14 @@ mod tests {
     fn parse(src: &str) -> Result<ConstructorDeclaration, ()> {
         let token_stream = tokenize(src);
 
-        ConstructorDeclaration::parser(Statement::parser())
-            .parse(token_stream)
-            .into_result()
-            .map_err(|_| ())
+        let statement_parser = Statement::parser();
+        ConstructorDeclaration::parser(
+            statement_parser.clone(),
+            Expression::parser(statement_parser),
+        )
+        .parse(token_stream)
+        .into_result()
+        .map_err(|_| ())
     }
 
     #[test]
@@ -89,6 +102,7 @@ mod tests {
         assert!(matches!(
             res,
             Ok(ConstructorDeclaration {
+                attributes: _,
                 modifiers: _,
                 reference: _,
                 parameters: _,
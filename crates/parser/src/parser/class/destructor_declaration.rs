@@ -1,7 +1,10 @@
 use chumsky::{input::ValueInput, span::SimpleSpan};
 use chumsky::{prelude::*, Parser};
 
+use crate::parser::atoms::attribute::AttributeGroup;
 use crate::parser::atoms::method_modifier::MethodModifier;
+use crate::parser::expressions::Expression;
+use crate::parser::keyword;
 use crate::parser::statements::compound_statement::CompoundStatement;
 use crate::parser::statements::Statement;
 use crate::parser::BoxedParser;
@@ -9,6 +12,7 @@ use phprs_lexer::Token;
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct DestructorDeclaration<'a> {
+    attributes: Vec<AttributeGroup<'a>>,
     modifiers: Vec<MethodModifier>,
     reference: bool,
     body: Option<CompoundStatement<'a>>,
@@ -17,15 +21,18 @@ pub struct DestructorDeclaration<'a> {
 impl<'a> DestructorDeclaration<'a> {
     pub fn parser<I>(
         statement_parser: BoxedParser<'a, I, Statement<'a>>,
+        expression_parser: BoxedParser<'a, I, Expression<'a>>,
     ) -> BoxedParser<'a, I, Self>
     where
         I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
     {
+        let attributes = AttributeGroup::list_parser(expression_parser);
         let modifiers = MethodModifier::list_parser();
         let reference = just(Token::Ampersand).or_not().map(|n| n.is_some());
 
-        let header = modifiers
-            .then_ignore(just(Token::FunctionKeyword))
+        let header = attributes
+            .then(modifiers)
+            .then_ignore(keyword(Token::FunctionKeyword))
             .then(reference)
             .then_ignore(just(Token::DestructKeyword))
             .then_ignore(just(Token::OpenParen).then_ignore(just(Token::CloseParen)));
@@ -37,7 +44,8 @@ impl<'a> DestructorDeclaration<'a> {
 
         header
             .then(body)
-            .map(|((modifiers, reference), body)| Self {
+            .map(|(((attributes, modifiers), reference), body)| Self {
+                attributes,
                 modifiers,
                 reference,
                 body,
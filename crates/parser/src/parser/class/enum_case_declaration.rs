@@ -1,36 +1,41 @@
 use chumsky::{input::ValueInput, span::SimpleSpan};
 use chumsky::{prelude::*, Parser};
 
+use crate::parser::atoms::attribute::AttributeGroup;
 use crate::parser::atoms::name::Name;
 use crate::parser::atoms::visibility_modifier::VisibilityModifier;
 use crate::parser::expressions::Expression;
-use crate::parser::statements::Statement;
+use crate::parser::keyword;
 use crate::parser::BoxedParser;
 use phprs_lexer::Token;
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct EnumCaseDeclaration<'a> {
+    attributes: Vec<AttributeGroup<'a>>,
     visibility: Option<VisibilityModifier>,
     name: Name<'a>,
-    expression: Expression<'a>,
+    // NOTE: Only backed enums have a value
+    expression: Option<Expression<'a>>,
 }
 
 impl<'a> EnumCaseDeclaration<'a> {
     pub fn parser<I>(
-        statement_parser: BoxedParser<'a, I, Statement<'a>>,
+        expression_parser: BoxedParser<'a, I, Expression<'a>>,
     ) -> impl Parser<'a, I, Self, extra::Err<Rich<'a, Token<'a>>>> + Clone
     where
         I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
     {
+        let attributes = AttributeGroup::list_parser(expression_parser.clone());
         let visiblity = VisibilityModifier::parser().or_not();
 
-        visiblity
-            .then_ignore(just(Token::CaseKeyword))
-            .then(Name::parser())
-            .then_ignore(just(Token::Equals))
-            .then(Expression::parser(statement_parser))
+        attributes
+            .then(visiblity)
+            .then_ignore(keyword(Token::CaseKeyword))
+            .then(Name::identifier_parser())
+            .then(just(Token::Equals).ignore_then(expression_parser).or_not())
             .then_ignore(just(Token::Semicolon))
-            .map(|((visibility, name), expression)| Self {
+            .map(|(((attributes, visibility), name), expression)| Self {
+                attributes,
                 visibility,
                 name,
                 expression,
@@ -1,6 +1,7 @@
 use chumsky::{input::ValueInput, span::SimpleSpan};
 use chumsky::{prelude::*, Parser};
 
+use crate::parser::expressions::Expression;
 use crate::parser::statements::Statement;
 use crate::parser::BoxedParser;
 use phprs_lexer::Token;
@@ -8,28 +9,39 @@ use phprs_lexer::Token;
 use super::class_const_declaration::ClassConstDeclaration;
 use super::enum_case_declaration::EnumCaseDeclaration;
 use super::method_declaration::MethodDeclaration;
+use super::trait_use_clause::TraitUseClause;
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub enum EnumMemberDeclaration<'a> {
     MethodDeclaration(MethodDeclaration<'a>),
     ClassConstDeclaration(ClassConstDeclaration<'a>),
     CaseDeclaration(EnumCaseDeclaration<'a>),
+    TraitUseClause(TraitUseClause<'a>),
 }
 
 impl<'a> EnumMemberDeclaration<'a> {
     pub fn parser<I>(
         statement_parser: BoxedParser<'a, I, Statement<'a>>,
+        expression_parser: BoxedParser<'a, I, Expression<'a>>,
     ) -> impl Parser<'a, I, Self, extra::Err<Rich<'a, Token<'a>>>> + Clone
     where
         I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
     {
         let method_declaration =
-            MethodDeclaration::parser(statement_parser.clone()).map(Self::MethodDeclaration);
-        let const_declaration = ClassConstDeclaration::parser(statement_parser.clone())
+            MethodDeclaration::parser(statement_parser.clone(), expression_parser.clone())
+                .map(Self::MethodDeclaration);
+        let const_declaration = ClassConstDeclaration::parser(expression_parser.clone())
             .map(Self::ClassConstDeclaration);
         let case_declaration =
-            EnumCaseDeclaration::parser(statement_parser).map(Self::CaseDeclaration);
+            EnumCaseDeclaration::parser(expression_parser).map(Self::CaseDeclaration);
+        let trait_use_clause = TraitUseClause::parser().map(Self::TraitUseClause);
 
-        choice((method_declaration, const_declaration, case_declaration))
+        choice((
+            method_declaration,
+            const_declaration,
+            case_declaration,
+            trait_use_clause,
+        ))
+        .labelled("EnumMemberDeclaration")
     }
 }
@@ -1,11 +1,14 @@
 use chumsky::{input::ValueInput, span::SimpleSpan};
 use chumsky::{prelude::*, Parser};
 
+use crate::parser::atoms::attribute::AttributeGroup;
 use crate::parser::atoms::method_modifier::MethodModifier;
 use crate::parser::atoms::name::Name;
 use crate::parser::atoms::parameter::parameter_declaration::ParameterDeclaration;
 use crate::parser::atoms::parameter::variadic_parameter::VariadicParameter;
 use crate::parser::atoms::r#type::return_type::ReturnType;
+use crate::parser::expressions::Expression;
+use crate::parser::keyword;
 use crate::parser::statements::compound_statement::CompoundStatement;
 use crate::parser::statements::function_definition::FunctionDefinition;
 use crate::parser::statements::Statement;
@@ -14,6 +17,7 @@ use phprs_lexer::Token;
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct MethodDeclaration<'a> {
+    attributes: Vec<AttributeGroup<'a>>,
     name: Name<'a>,
     modifiers: Vec<MethodModifier>,
     reference: bool,
@@ -27,22 +31,23 @@ impl<'a> MethodDeclaration<'a> {
     // TODO: Should directly use FunctionDefinition::parser() once header is splitted
     pub fn parser<I>(
         statement_parser: BoxedParser<'a, I, Statement<'a>>,
+        expression_parser: BoxedParser<'a, I, Expression<'a>>,
     ) -> BoxedParser<'a, I, Self>
     where
         I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
     {
+        let attributes = AttributeGroup::list_parser(expression_parser.clone());
         let modifiers = MethodModifier::list_parser();
         let reference = just(Token::Ampersand).or_not().map(|n| n.is_some());
 
-        let header = modifiers
-            .then_ignore(just(Token::FunctionKeyword))
+        let header = attributes
+            .then(modifiers)
+            .then_ignore(keyword(Token::FunctionKeyword))
             .then(reference)
-            .then(Name::parser())
+            .then(Name::identifier_parser())
             .then(
                 just(Token::OpenParen)
-                    .ignore_then(FunctionDefinition::parameters_parser(
-                        statement_parser.clone(),
-                    ))
+                    .ignore_then(FunctionDefinition::parameters_parser(expression_parser))
                     .then_ignore(just(Token::CloseParen)),
             )
             .then(ReturnType::parser().or_not());
@@ -56,10 +61,14 @@ impl<'a> MethodDeclaration<'a> {
             .then(body)
             .map(
                 |(
-                    ((((modifiers, reference), name), (parameters, variadic)), return_type),
+                    (
+                        ((((attributes, modifiers), reference), name), (parameters, variadic)),
+                        return_type,
+                    ),
                     body,
                 )| {
                     Self {
+                        attributes,
                         name,
                         modifiers,
                         reference,
@@ -6,4 +6,5 @@ pub mod enum_case_declaration;
 pub mod enum_member_declaration;
 pub mod method_declaration;
 pub mod property_declaration;
+pub mod property_hook;
 pub mod trait_use_clause;
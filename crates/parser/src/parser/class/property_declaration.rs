@@ -1,28 +1,153 @@
 use chumsky::{input::ValueInput, span::SimpleSpan};
 use chumsky::{prelude::*, Parser};
 
+use crate::parser::atoms::attribute::AttributeGroup;
 use crate::parser::atoms::property_element::PropertyElement;
 use crate::parser::atoms::property_modifier::PropertyModifier;
+use crate::parser::atoms::r#type::type_declaration::TypeDeclaration;
+use crate::parser::class::property_hook::PropertyHook;
+use crate::parser::expressions::Expression;
 use crate::parser::statements::Statement;
 use crate::parser::BoxedParser;
 use phprs_lexer::Token;
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct PropertyDeclaration<'a> {
-    modifier: PropertyModifier,
+    attributes: Vec<AttributeGroup<'a>>,
+    modifiers: Vec<PropertyModifier>,
+    r#type: Option<TypeDeclaration<'a>>,
     elements: Vec<PropertyElement<'a>>,
+    hooks: Vec<PropertyHook<'a>>,
 }
 
 impl<'a> PropertyDeclaration<'a> {
     pub fn parser<I>(
         statement_parser: BoxedParser<'a, I, Statement<'a>>,
+        expression_parser: BoxedParser<'a, I, Expression<'a>>,
     ) -> impl Parser<'a, I, Self, extra::Err<Rich<'a, Token<'a>>>> + Clone
     where
         I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
     {
-        PropertyModifier::parser()
-            .then(PropertyElement::list_parser(statement_parser))
-            .then_ignore(just(Token::Semicolon))
-            .map(|(modifier, elements)| Self { modifier, elements })
+        let hooks = PropertyHook::list_parser(statement_parser, expression_parser.clone());
+
+        AttributeGroup::list_parser(expression_parser.clone())
+            .then(PropertyModifier::list_parser())
+            .then(TypeDeclaration::parser().or_not())
+            .then(PropertyElement::list_parser(expression_parser))
+            .then(choice((just(Token::Semicolon).to(vec![]), hooks)))
+            .validate(
+                |((((attributes, modifiers), r#type), elements), hooks), e, emitter| {
+                    if !hooks.is_empty() && elements.len() > 1 {
+                        emitter.emit(Rich::custom(
+                            e.span(),
+                            "Cannot use hooks when declaring multiple properties",
+                        ));
+                    }
+
+                    Self {
+                        attributes,
+                        modifiers,
+                        r#type,
+                        elements,
+                        hooks,
+                    }
+                },
+            )
+    }
+
+    pub fn hooks(&self) -> &[PropertyHook<'a>] {
+        &self.hooks
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::atoms::visibility_modifier::VisibilityModifier;
+    use crate::parser::tokenize;
+
+    use super::*;
+
+    fn parse(src: &str) -> Result<PropertyDeclaration<'_>, ()> {
+        let tokens = tokenize(src);
+
+        let statement_parser = Statement::parser();
+        PropertyDeclaration::parser(
+            statement_parser.clone(),
+            Expression::parser(statement_parser),
+        )
+        .parse(tokens)
+        .into_result()
+        .map_err(|_| ())
+    }
+
+    #[test]
+    fn typed() {
+        let res = parse(r#"private static ?int $count = 0;"#).unwrap();
+        assert!(res.r#type.is_some());
+        assert_eq!(res.elements.len(), 1);
+    }
+
+    #[test]
+    fn readonly() {
+        let res = parse(r#"public readonly string $name;"#).unwrap();
+        assert_eq!(
+            res.modifiers,
+            vec![
+                PropertyModifier::Visibility(VisibilityModifier::Public),
+                PropertyModifier::Readonly
+            ]
+        );
+    }
+
+    #[test]
+    fn asymmetric_visibility() {
+        let res = parse(r#"public private(set) int $id;"#).unwrap();
+        assert_eq!(
+            res.modifiers,
+            vec![
+                PropertyModifier::Visibility(VisibilityModifier::Public),
+                PropertyModifier::SetVisibility(VisibilityModifier::Private)
+            ]
+        );
+
+        assert!(parse(r#"protected(set) string $name = 'x';"#).is_ok());
+    }
+
+    #[test]
+    fn hooks() {
+        let res = parse(
+            r#"public string $name {
+                get => $this->first . ' ' . $this->last;
+                set(string $value) {
+                    [$this->first, $this->last] = explode(' ', $value, 2);
+                }
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(res.elements.len(), 1);
+        assert_eq!(res.hooks.len(), 2);
+    }
+
+    #[test]
+    fn hooks_with_default() {
+        let res = parse(r#"public int $count = 0 { set => max(0, $value); }"#).unwrap();
+        assert!(res.elements[0].initializer.is_some());
+        assert_eq!(res.hooks.len(), 1);
+    }
+
+    #[test]
+    fn empty_hooks_fail() {
+        assert_eq!(parse(r#"public string $name {}"#), Err(()));
+    }
+
+    #[test]
+    fn hooks_multiple_properties_fail() {
+        assert_eq!(parse(r#"public string $a, $b { get; }"#), Err(()));
+    }
+
+    #[test]
+    fn without_modifier_fail() {
+        let res = parse(r#"string $name;"#);
+        assert_eq!(res, Err(()));
     }
 }
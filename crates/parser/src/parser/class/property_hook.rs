@@ -0,0 +1,167 @@
+use chumsky::{input::ValueInput, span::SimpleSpan};
+use chumsky::{prelude::*, Parser};
+
+use crate::parser::atoms::attribute::AttributeGroup;
+use crate::parser::atoms::parameter::parameter_declaration::ParameterDeclaration;
+use crate::parser::expressions::Expression;
+use crate::parser::keyword;
+use crate::parser::statements::compound_statement::CompoundStatement;
+use crate::parser::statements::Statement;
+use crate::parser::BoxedParser;
+use phprs_lexer::Token;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PropertyHookKind {
+    Get,
+    Set,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PropertyHookBody<'a> {
+    // `get;` in abstract properties and interfaces
+    None,
+    // `get => $this->name;`
+    Expression(Expression<'a>),
+    // `set { ... }`
+    Block(CompoundStatement<'a>),
+}
+
+// PHP 8.4 `public string $name { get => ...; set { ... } }`
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PropertyHook<'a> {
+    pub attributes: Vec<AttributeGroup<'a>>,
+    pub r#final: bool,
+    pub reference: bool,
+    pub kind: PropertyHookKind,
+    // `set(string $value)`
+    pub parameters: Option<Vec<ParameterDeclaration<'a>>>,
+    pub body: PropertyHookBody<'a>,
+}
+
+impl<'a> PropertyHook<'a> {
+    pub fn parser<I>(
+        statement_parser: BoxedParser<'a, I, Statement<'a>>,
+        expression_parser: BoxedParser<'a, I, Expression<'a>>,
+    ) -> impl Parser<'a, I, Self, extra::Err<Rich<'a, Token<'a>>>> + Clone
+    where
+        I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
+    {
+        let attributes = AttributeGroup::list_parser(expression_parser.clone());
+        let r#final = keyword(Token::FinalKeyword).or_not().map(|t| t.is_some());
+        let reference = just(Token::Ampersand).or_not().map(|t| t.is_some());
+        let kind = select! {
+            Token::Name(name) if name.eq_ignore_ascii_case("get") => PropertyHookKind::Get,
+            Token::Name(name) if name.eq_ignore_ascii_case("set") => PropertyHookKind::Set,
+        };
+        let parameters = just(Token::OpenParen)
+            .ignore_then(ParameterDeclaration::list_parser(expression_parser.clone()))
+            .then_ignore(just(Token::CloseParen))
+            .or_not();
+
+        let body = choice((
+            just(Token::Semicolon).to(PropertyHookBody::None),
+            just(Token::DoubleArrow)
+                .ignore_then(expression_parser)
+                .then_ignore(just(Token::Semicolon))
+                .map(PropertyHookBody::Expression),
+            CompoundStatement::parser(statement_parser).map(PropertyHookBody::Block),
+        ));
+
+        attributes
+            .then(r#final)
+            .then(reference)
+            .then(kind)
+            .then(parameters)
+            .then(body)
+            .map(
+                |(((((attributes, r#final), reference), kind), parameters), body)| Self {
+                    attributes,
+                    r#final,
+                    reference,
+                    kind,
+                    parameters,
+                    body,
+                },
+            )
+            .labelled("PropertyHook")
+            .boxed()
+    }
+
+    // `{ get; set; }` after a property or a promoted constructor parameter
+    pub fn list_parser<I>(
+        statement_parser: BoxedParser<'a, I, Statement<'a>>,
+        expression_parser: BoxedParser<'a, I, Expression<'a>>,
+    ) -> impl Parser<'a, I, Vec<Self>, extra::Err<Rich<'a, Token<'a>>>> + Clone
+    where
+        I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
+    {
+        Self::parser(statement_parser, expression_parser)
+            .repeated()
+            .collect::<Vec<_>>()
+            .delimited_by(just(Token::OpenBrace), just(Token::CloseBrace))
+            .validate(|hooks, e, emitter| {
+                if hooks.is_empty() {
+                    emitter.emit(Rich::custom(
+                        e.span(),
+                        "Property hook list must not be empty",
+                    ));
+                }
+
+                hooks
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::tokenize;
+
+    use super::*;
+
+    fn parse(src: &str) -> Result<PropertyHook<'_>, ()> {
+        let tokens = tokenize(src);
+
+        let statement_parser = Statement::parser();
+        PropertyHook::parser(
+            statement_parser.clone(),
+            Expression::parser(statement_parser),
+        )
+        .parse(tokens)
+        .into_result()
+        .map_err(|_| ())
+    }
+
+    #[test]
+    fn short_get() {
+        let res = parse(r#"get => $this->first . ' ' . $this->last;"#).unwrap();
+        assert_eq!(res.kind, PropertyHookKind::Get);
+        assert!(matches!(res.body, PropertyHookBody::Expression(_)));
+    }
+
+    #[test]
+    fn block_set() {
+        let res = parse(
+            r#"set(string $value) {
+                $this->name = strtolower($value);
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(res.kind, PropertyHookKind::Set);
+        assert!(matches!(res.parameters.as_deref(), Some([_])));
+        assert!(matches!(res.body, PropertyHookBody::Block(_)));
+    }
+
+    #[test]
+    fn abstract_() {
+        let res = parse(r#"#[Pure] final &get;"#).unwrap();
+        assert_eq!(res.attributes.len(), 1);
+        assert!(res.r#final);
+        assert!(res.reference);
+        assert_eq!(res.body, PropertyHookBody::None);
+    }
+
+    #[test]
+    fn unknown_hook_fail() {
+        assert_eq!(parse(r#"delete => null;"#), Err(()));
+    }
+}
@@ -2,27 +2,233 @@ use chumsky::{input::ValueInput, span::SimpleSpan};
 use chumsky::{prelude::*, Parser};
 
 use crate::parser::atoms::name::qualified_name::QualifiedName;
+use crate::parser::atoms::name::Name;
+use crate::parser::atoms::visibility_modifier::VisibilityModifier;
+use crate::parser::keyword;
 use phprs_lexer::Token;
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct TraitUseClause<'a> {
-    names: Vec<QualifiedName<'a>>,
+    pub names: Vec<QualifiedName<'a>>,
+    pub adaptations: Vec<TraitAdaptation<'a>>,
+}
+
+// `A::foo` or `foo`
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TraitMethodReference<'a> {
+    pub r#trait: Option<QualifiedName<'a>>,
+    pub method: Name<'a>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TraitAdaptation<'a> {
+    // `A::foo insteadof B, C;`
+    Precedence {
+        r#trait: QualifiedName<'a>,
+        method: Name<'a>,
+        instead_of: Vec<QualifiedName<'a>>,
+    },
+    // `B::foo as protected bar;`
+    Alias {
+        method: TraitMethodReference<'a>,
+        visibility: Option<VisibilityModifier>,
+        alias: Option<Name<'a>>,
+    },
 }
 
 impl<'a> TraitUseClause<'a> {
-    pub fn parser<I>(// statement_parser: BoxedParser<'a, I, Statement<'a>>,
-    ) -> impl Parser<'a, I, Self, extra::Err<Rich<'a, Token<'a>>>> + Clone
+    pub fn parser<I>() -> impl Parser<'a, I, Self, extra::Err<Rich<'a, Token<'a>>>> + Clone
     where
         I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
     {
         let names = QualifiedName::parser()
             .separated_by(just(Token::Comma))
+            .at_least(1)
             .collect();
-        // TODO trait-use-specification
 
-        just(Token::UseKeyword)
+        let adaptations = just(Token::OpenBrace)
+            .ignore_then(
+                TraitAdaptation::parser()
+                    .then_ignore(just(Token::Semicolon))
+                    .repeated()
+                    .collect(),
+            )
+            .then_ignore(just(Token::CloseBrace));
+
+        keyword(Token::UseKeyword)
             .ignore_then(names)
-            .then_ignore(just(Token::Semicolon))
-            .map(|names| Self { names })
+            .then(choice((just(Token::Semicolon).to(vec![]), adaptations)))
+            .map(|(names, adaptations)| Self { names, adaptations })
+            .labelled("TraitUseClause")
+    }
+}
+
+impl<'a> TraitAdaptation<'a> {
+    pub fn parser<I>() -> impl Parser<'a, I, Self, extra::Err<Rich<'a, Token<'a>>>> + Clone
+    where
+        I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
+    {
+        let precedence = QualifiedName::parser()
+            .then_ignore(just(Token::ColonColon))
+            .then(Name::identifier_parser())
+            .then_ignore(keyword(Token::InsteadOfKeyword))
+            .then(
+                QualifiedName::parser()
+                    .separated_by(just(Token::Comma))
+                    .at_least(1)
+                    .collect(),
+            )
+            .map(|((r#trait, method), instead_of)| Self::Precedence {
+                r#trait,
+                method,
+                instead_of,
+            });
+
+        let method = QualifiedName::parser()
+            .then_ignore(just(Token::ColonColon))
+            .or_not()
+            .then(Name::identifier_parser())
+            .map(|(r#trait, method)| TraitMethodReference { r#trait, method });
+
+        let alias = method
+            .then_ignore(keyword(Token::AsKeyword))
+            .then(VisibilityModifier::parser().or_not())
+            .then(Name::identifier_parser().or_not())
+            .validate(|((method, visibility), alias), e, emitter| {
+                if visibility.is_none() && alias.is_none() {
+                    emitter.emit(Rich::custom(
+                        e.span(),
+                        "Trait alias must change the method name or visibility",
+                    ));
+                }
+
+                Self::Alias {
+                    method,
+                    visibility,
+                    alias,
+                }
+            });
+
+        choice((precedence, alias)).labelled("TraitAdaptation")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::tokenize;
+
+    use super::*;
+
+    fn parse(src: &str) -> Result<TraitUseClause<'_>, ()> {
+        let tokens = tokenize(src);
+
+        TraitUseClause::parser()
+            .parse(tokens)
+            .into_result()
+            .map_err(|_| ())
+    }
+
+    #[test]
+    fn simple() {
+        let res = parse(r#"use Loggable, Countable;"#).unwrap();
+        assert_eq!(res.names.len(), 2);
+        assert!(res.adaptations.is_empty());
+    }
+
+    #[test]
+    fn empty_block() {
+        let res = parse(r#"use Loggable {}"#).unwrap();
+        assert!(res.adaptations.is_empty());
+    }
+
+    #[test]
+    fn precedence() {
+        let res = parse(r#"use A, B, C { A::foo insteadof B, C; }"#).unwrap();
+        assert!(matches!(
+            &res.adaptations[..],
+            [TraitAdaptation::Precedence { method: Name("foo"), instead_of, .. }] if instead_of.len() == 2
+        ));
+    }
+
+    #[test]
+    fn alias() {
+        let res = parse(
+            r#"use A, B {
+                A::foo insteadof B;
+                B::foo as protected bar;
+                baz as qux;
+                hello as private;
+            }"#,
+        )
+        .unwrap();
+
+        assert!(matches!(
+            &res.adaptations[..],
+            [
+                TraitAdaptation::Precedence { .. },
+                TraitAdaptation::Alias {
+                    method: TraitMethodReference {
+                        r#trait: Some(_),
+                        method: Name("foo")
+                    },
+                    visibility: Some(VisibilityModifier::Protected),
+                    alias: Some(Name("bar")),
+                },
+                TraitAdaptation::Alias {
+                    method: TraitMethodReference {
+                        r#trait: None,
+                        method: Name("baz")
+                    },
+                    visibility: None,
+                    alias: Some(Name("qux")),
+                },
+                TraitAdaptation::Alias {
+                    visibility: Some(VisibilityModifier::Private),
+                    alias: None,
+                    ..
+                },
+            ]
+        ));
+    }
+
+    #[test]
+    fn keyword_alias() {
+        let res = parse(
+            r#"use A {
+                foo as list;
+                bar as function;
+                A::baz as protected Default;
+            }"#,
+        )
+        .unwrap();
+
+        assert!(matches!(
+            &res.adaptations[..],
+            [
+                TraitAdaptation::Alias {
+                    alias: Some(Name("list")),
+                    ..
+                },
+                TraitAdaptation::Alias {
+                    alias: Some(Name("function")),
+                    ..
+                },
+                TraitAdaptation::Alias {
+                    visibility: Some(VisibilityModifier::Protected),
+                    alias: Some(Name("Default")),
+                    ..
+                },
+            ]
+        ));
+    }
+
+    #[test]
+    fn alias_without_change_fail() {
+        assert_eq!(parse(r#"use A { foo as; }"#), Err(()));
+    }
+
+    #[test]
+    fn unqualified_precedence_fail() {
+        assert_eq!(parse(r#"use A, B { foo insteadof B; }"#), Err(()));
     }
 }
@@ -1,3 +1,4 @@
+use crate::parser::keyword;
 use chumsky::input::ValueInput;
 use chumsky::pratt::*;
 use chumsky::prelude::*;
@@ -5,22 +6,32 @@ use chumsky::Parser;
 use phprs_lexer::Token;
 use primary_expression::PrimaryExpression;
 
+use super::atoms::cast_type::CastType;
+use super::atoms::class_type_designator::ClassTypeDesignator;
+use super::atoms::compound_assignment_operator::CompoundAssignmentOperator;
+use super::atoms::intrinsic::list_intrinsic::ListIntrinsic;
 use super::statements::Statement;
 use super::BoxedParser;
 
 pub mod anonymous_function_expression;
 pub mod argument_expression;
 pub mod array_creation_expression;
+pub mod arrow_function_expression;
 pub mod byref_assignment_expression;
+pub mod callable_creation_expression;
 pub mod callable_expression;
 pub mod class_constant_access_expression;
 pub mod constant_access_expression;
 pub mod decrement_expression;
 pub mod dereferencable_expression;
+pub mod function_call_expression;
 pub mod include_expression;
 pub mod include_once_expression;
 pub mod increment_expression;
+pub mod interpolated_string_expression;
 pub mod literal_expression;
+pub mod magic_constant_expression;
+pub mod match_expression;
 pub mod member_access_expression;
 pub mod member_call_expression;
 pub mod object_creation_expression;
@@ -28,7 +39,9 @@ pub mod primary_expression;
 pub mod require_expression;
 pub mod require_once_expression;
 pub mod reserved_word_expression;
+pub mod scoped_call_expression;
 pub mod scoped_property_access_expression;
+pub mod shell_command_expression;
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub enum Expression<'a> {
@@ -39,8 +52,8 @@ pub enum Expression<'a> {
     Neg(Box<Self>),
     BitwiseNot(Box<Self>),
     ErrorControl(Box<Self>),
-    Cast(Box<Self>),       // TODO
-    InstanceOf(Box<Self>), // TODO
+    Cast(CastType, Box<Self>),
+    InstanceOf(Box<Self>, Box<ClassTypeDesignator<'a>>),
     LogicalNot(Box<Self>),
     Multiply(Box<Self>, Box<Self>),
     Divide(Box<Self>, Box<Self>),
@@ -68,6 +81,24 @@ pub enum Expression<'a> {
     Coalesce(Box<Self>, Box<Self>),
     Conditional(Box<Self>, Box<Option<Expression<'a>>>, Box<Self>),
     Assignment(Box<Self>, Box<Self>),
+    // `[$a, $b] = $pair` or `list($a, $b) = $pair`
+    ListAssignment(ListIntrinsic<'a>, Box<Self>),
+    CompoundAssignment {
+        op: CompoundAssignmentOperator,
+        target: Box<Self>,
+        value: Box<Self>,
+    },
+    Yield(Option<Box<Self>>),
+    // `yield $key => $value`
+    YieldPair {
+        key: Box<Self>,
+        value: Box<Self>,
+    },
+    YieldFrom(Box<Self>),
+    Print(Box<Self>),
+    LogicalAnd(Box<Self>, Box<Self>),
+    LogicalXor(Box<Self>, Box<Self>),
+    LogicalOr(Box<Self>, Box<Self>),
 }
 
 impl<'a> Expression<'a> {
@@ -79,137 +110,239 @@ impl<'a> Expression<'a> {
         I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
     {
         recursive(|expression_parser| {
-            let atom =
+            let primary =
                 PrimaryExpression::parser(statement_parser, expression_parser.clone().boxed())
                     .map(Expression::Primary);
 
+            // NOTE: A `yield` without operand, the other forms are prefix operators
+            let yield_ = keyword(Token::YieldKeyword).to(Expression::Yield(None));
+
+            let atom = primary.or(yield_);
+
+            let instanceof_postfix = keyword(Token::InstanceOfKeyword).ignore_then(
+                ClassTypeDesignator::parser(expression_parser.clone().boxed()),
+            );
+
+            let yield_prefix = keyword(Token::YieldKeyword).ignore_then(
+                expression_parser
+                    .clone()
+                    .then_ignore(just(Token::DoubleArrow))
+                    .or_not(),
+            );
+
+            // NOTE: `[$a, $b] = $pair` goes through the `=` infix, see `Expression::assignment`
+            let list_assignment_prefix =
+                ListIntrinsic::keyword_parser(expression_parser.clone().boxed())
+                    .then_ignore(just(Token::Equals));
+
             let conditional_infix = just(Token::Question)
                 .ignore_then(expression_parser.or_not())
                 .then_ignore(just(Token::Colon));
 
+            // NOTE: Follows https://www.php.net/manual/en/language.operators.precedence.php,
+            // non-associative operators are parsed as left-associative and rejected afterwards
             atom.pratt((
                 // CLONE
-                prefix(99, just(Token::CloneKeyword), |_, r, _| {
+                prefix(30, keyword(Token::CloneKeyword), |_, r, _| {
                     Expression::Clone(Box::new(r))
                 }),
-                // UNARY
-                prefix(99, just(Token::Plus), |_, r, _| {
-                    Expression::Pos(Box::new(r))
-                }),
-                prefix(99, just(Token::Minus), |_, r, _| {
-                    Expression::Neg(Box::new(r))
-                }),
-                prefix(99, just(Token::Tilde), |_, r, _| {
-                    Expression::BitwiseNot(Box::new(r))
-                }),
-                prefix(99, just(Token::AtSymbol), |_, r, _| {
-                    Expression::ErrorControl(Box::new(r))
+                // EXPONENTIATION
+                infix(right(28), just(Token::AsteriskAsterisk), |l, _, r, _| {
+                    Expression::Power(Box::new(l), Box::new(r))
                 }),
+                // UNARY
+                (
+                    prefix(27, just(Token::Plus), |_, r, _| {
+                        Expression::Pos(Box::new(r))
+                    }),
+                    prefix(27, just(Token::Minus), |_, r, _| {
+                        Expression::Neg(Box::new(r))
+                    }),
+                    prefix(27, just(Token::Tilde), |_, r, _| {
+                        Expression::BitwiseNot(Box::new(r))
+                    }),
+                    prefix(27, just(Token::AtSymbol), |_, r, _| {
+                        Expression::ErrorControl(Box::new(r))
+                    }),
+                    prefix(27, CastType::parser(), |t, r, _| {
+                        Expression::Cast(t, Box::new(r))
+                    }),
+                ),
                 // INSTANCEOF
-                // TODO: prefix instanceof 98
+                postfix(26, instanceof_postfix, |l, d, _| {
+                    Expression::InstanceOf(Box::new(l), Box::new(d))
+                }),
                 // LOGICAL NOT
-                prefix(97, just(Token::Exclamation), |_, r, _| {
+                prefix(25, just(Token::Exclamation), |_, r, _| {
                     Expression::LogicalNot(Box::new(r))
                 }),
-                infix(left(99), just(Token::AsteriskAsterisk), |l, _, r, _| {
-                    Expression::Power(Box::new(l), Box::new(r))
-                }),
                 // MULTIPLICATIVE
-                infix(left(96), just(Token::Asterisk), |l, _, r, _| {
-                    Expression::Multiply(Box::new(l), Box::new(r))
-                }),
-                infix(left(96), just(Token::Percent), |l, _, r, _| {
-                    Expression::Modulo(Box::new(l), Box::new(r))
-                }),
+                (
+                    infix(left(24), just(Token::Asterisk), |l, _, r, _| {
+                        Expression::Multiply(Box::new(l), Box::new(r))
+                    }),
+                    infix(left(24), just(Token::Slash), |l, _, r, _| {
+                        Expression::Divide(Box::new(l), Box::new(r))
+                    }),
+                    infix(left(24), just(Token::Percent), |l, _, r, _| {
+                        Expression::Modulo(Box::new(l), Box::new(r))
+                    }),
+                ),
                 // ADDITIVE
-                infix(left(95), just(Token::Plus), |l, _, r, _| {
-                    Expression::Add(Box::new(l), Box::new(r))
-                }),
-                infix(left(95), just(Token::Minus), |l, _, r, _| {
-                    Expression::Sub(Box::new(l), Box::new(r))
-                }),
-                infix(left(95), just(Token::Dot), |l, _, r, _| {
-                    Expression::Concatenate(Box::new(l), Box::new(r))
-                }),
+                (
+                    infix(left(23), just(Token::Plus), |l, _, r, _| {
+                        Expression::Add(Box::new(l), Box::new(r))
+                    }),
+                    infix(left(23), just(Token::Minus), |l, _, r, _| {
+                        Expression::Sub(Box::new(l), Box::new(r))
+                    }),
+                ),
                 // SHIFT
-                infix(left(94), just(Token::LessThanLessThan), |l, _, r, _| {
-                    Expression::LeftShift(Box::new(l), Box::new(r))
-                }),
-                infix(
-                    left(94),
-                    just(Token::GreaterThanGreaterThan),
-                    |l, _, r, _| Expression::RightShift(Box::new(l), Box::new(r)),
+                (
+                    infix(left(22), just(Token::LessThanLessThan), |l, _, r, _| {
+                        Expression::LeftShift(Box::new(l), Box::new(r))
+                    }),
+                    infix(
+                        left(22),
+                        just(Token::GreaterThanGreaterThan),
+                        |l, _, r, _| Expression::RightShift(Box::new(l), Box::new(r)),
+                    ),
                 ),
-                // RELATIONAL
-                infix(left(93), just(Token::LessThan), |l, _, r, _| {
-                    Expression::LessThan(Box::new(l), Box::new(r))
-                }),
-                infix(left(93), just(Token::GreatherThan), |l, _, r, _| {
-                    Expression::GreaterThan(Box::new(l), Box::new(r))
-                }),
-                infix(left(93), just(Token::LessThanEqual), |l, _, r, _| {
-                    Expression::LessThanOrEqual(Box::new(l), Box::new(r))
-                }),
-                infix(left(93), just(Token::GreaterThanEquals), |l, _, r, _| {
-                    Expression::GreaterThanOrEqual(Box::new(l), Box::new(r))
+                // CONCATENATION
+                infix(left(21), just(Token::Dot), |l, _, r, _| {
+                    Expression::Concatenate(Box::new(l), Box::new(r))
                 }),
-                infix(
-                    left(93),
-                    just(Token::LessThanEqualsGreaterThan),
-                    |l, _, r, _| Expression::Spaceship(Box::new(l), Box::new(r)),
+                // RELATIONAL
+                (
+                    infix(left(20), just(Token::LessThan), |l, _, r, _| {
+                        Expression::LessThan(Box::new(l), Box::new(r))
+                    }),
+                    infix(left(20), just(Token::GreatherThan), |l, _, r, _| {
+                        Expression::GreaterThan(Box::new(l), Box::new(r))
+                    }),
+                    infix(left(20), just(Token::LessThanEqual), |l, _, r, _| {
+                        Expression::LessThanOrEqual(Box::new(l), Box::new(r))
+                    }),
+                    infix(left(20), just(Token::GreaterThanEquals), |l, _, r, _| {
+                        Expression::GreaterThanOrEqual(Box::new(l), Box::new(r))
+                    }),
                 ),
                 // EQUALITY
-                infix(left(94), just(Token::EqualsEquals), |l, _, r, _| {
-                    Expression::Equal(Box::new(l), Box::new(r))
-                }),
-                infix(left(94), just(Token::ExclamationEquals), |l, _, r, _| {
-                    Expression::NotEqual(Box::new(l), Box::new(r))
-                }),
-                infix(left(94), just(Token::LessThanGreaterThan), |l, _, r, _| {
-                    Expression::Inequal(Box::new(l), Box::new(r))
-                }),
-                infix(left(94), just(Token::EqualsEqualsEquals), |l, _, r, _| {
-                    Expression::StrictEqual(Box::new(l), Box::new(r))
-                }),
-                infix(
-                    left(94),
-                    just(Token::ExclamationEqualsEquals),
-                    |l, _, r, _| Expression::StrictNotEqual(Box::new(l), Box::new(r)),
+                (
+                    infix(left(19), just(Token::EqualsEquals), |l, _, r, _| {
+                        Expression::Equal(Box::new(l), Box::new(r))
+                    }),
+                    infix(left(19), just(Token::ExclamationEquals), |l, _, r, _| {
+                        Expression::NotEqual(Box::new(l), Box::new(r))
+                    }),
+                    infix(left(19), just(Token::LessThanGreaterThan), |l, _, r, _| {
+                        Expression::Inequal(Box::new(l), Box::new(r))
+                    }),
+                    infix(left(19), just(Token::EqualsEqualsEquals), |l, _, r, _| {
+                        Expression::StrictEqual(Box::new(l), Box::new(r))
+                    }),
+                    infix(
+                        left(19),
+                        just(Token::ExclamationEqualsEquals),
+                        |l, _, r, _| Expression::StrictNotEqual(Box::new(l), Box::new(r)),
+                    ),
+                    infix(
+                        left(19),
+                        just(Token::LessThanEqualsGreaterThan),
+                        |l, _, r, _| Expression::Spaceship(Box::new(l), Box::new(r)),
+                    ),
                 ),
                 // BITWISE AND
-                infix(left(93), just(Token::Ampersand), |l, _, r, _| {
+                infix(left(18), just(Token::Ampersand), |l, _, r, _| {
                     Expression::BitwiseAnd(Box::new(l), Box::new(r))
                 }),
                 // BITWISE EXC
-                infix(left(92), just(Token::Caret), |l, _, r, _| {
+                infix(left(17), just(Token::Caret), |l, _, r, _| {
                     Expression::BitwiseExc(Box::new(l), Box::new(r))
                 }),
                 // BITWISE INC
-            ))
-            .pratt((
-                infix(left(91), just(Token::Bar), |l, _, r, _| {
+                infix(left(16), just(Token::Bar), |l, _, r, _| {
                     Expression::BitwiseInc(Box::new(l), Box::new(r))
                 }),
-                infix(left(90), just(Token::AmpersandAmpersand), |l, _, r, _| {
+                infix(left(15), just(Token::AmpersandAmpersand), |l, _, r, _| {
                     Expression::And(Box::new(l), Box::new(r))
                 }),
-                infix(left(89), just(Token::BarBar), |l, _, r, _| {
+                infix(left(14), just(Token::BarBar), |l, _, r, _| {
                     Expression::Or(Box::new(l), Box::new(r))
                 }),
-                infix(left(88), just(Token::QuestionQuestion), |l, _, r, _| {
+                infix(right(13), just(Token::QuestionQuestion), |l, _, r, _| {
                     Expression::Coalesce(Box::new(l), Box::new(r))
                 }),
                 infix(
-                    left(87),
+                    left(12),
                     conditional_infix,
                     |l, e: Option<Expression<'a>>, r, _| {
                         Expression::Conditional(Box::new(l), Box::new(e), Box::new(r))
                     },
                 ),
-                infix(left(86), just(Token::Equals), |l, _, r, _| {
-                    Expression::Assignment(Box::new(l), Box::new(r))
+                // ASSIGNMENT
+                (
+                    infix(
+                        right(11),
+                        just(Token::Equals),
+                        |l: Expression<'a>, _, r, _| l.assign(r, Expression::assignment),
+                    ),
+                    prefix(11, list_assignment_prefix, |list, r, _| {
+                        Expression::ListAssignment(list, Box::new(r))
+                    }),
+                    infix(
+                        right(11),
+                        CompoundAssignmentOperator::parser(),
+                        |l: Expression<'a>, op, r, _| {
+                            l.assign(r, |target, value| Expression::CompoundAssignment {
+                                op,
+                                target: Box::new(target),
+                                value: Box::new(value),
+                            })
+                        },
+                    ),
+                ),
+                // YIELD, PRINT
+                (
+                    prefix(10, just(Token::YieldFromKeyword), |_, r, _| {
+                        Expression::YieldFrom(Box::new(r))
+                    }),
+                    prefix(
+                        10,
+                        yield_prefix,
+                        |k: Option<Expression<'a>>, r, _| match k {
+                            Some(key) => Expression::YieldPair {
+                                key: Box::new(key),
+                                value: Box::new(r),
+                            },
+                            None => Expression::Yield(Some(Box::new(r))),
+                        },
+                    ),
+                    prefix(10, keyword(Token::PrintKeyword), |_, r, _| {
+                        Expression::Print(Box::new(r))
+                    }),
+                ),
+                // LOGICAL KEYWORDS
+                infix(left(9), keyword(Token::AndKeyword), |l, _, r, _| {
+                    Expression::LogicalAnd(Box::new(l), Box::new(r))
+                }),
+                infix(left(8), keyword(Token::XorKeyword), |l, _, r, _| {
+                    Expression::LogicalXor(Box::new(l), Box::new(r))
+                }),
+                infix(left(7), keyword(Token::OrKeyword), |l, _, r, _| {
+                    Expression::LogicalOr(Box::new(l), Box::new(r))
                 }),
             ))
+            .validate(|expression: Expression<'a>, e, emitter| {
+                if expression.has_non_associative_chain() {
+                    emitter.emit(Rich::custom(
+                        e.span(),
+                        "Non-associative operators cannot be chained without parentheses",
+                    ));
+                }
+
+                expression
+            })
         })
         .labelled("Expression")
         .boxed()
@@ -226,10 +359,190 @@ impl<'a> Expression<'a> {
             .at_least(1)
             .collect()
     }
+
+    // NOTE: `[$a, $b]` is parsed as an array creation and only becomes a list once assigned to
+    // NOTE: Array targets that are not valid lists are reported by `PrimaryExpression`
+    fn assignment(target: Self, value: Self) -> Self {
+        if let Self::Primary(PrimaryExpression::ArrayCreation(array)) = &target {
+            if let Ok(list) = ListIntrinsic::from_array(array) {
+                return Self::ListAssignment(list, Box::new(value));
+            }
+        }
+
+        Self::Assignment(Box::new(target), Box::new(value))
+    }
+
+    // NOTE: Only variables can be assigned, so PHP applies `=` to the rightmost operand of the
+    // operators parsed before it: `!$a = f()` is `!($a = f())`
+    fn assign(self, value: Self, assignment: impl FnOnce(Self, Self) -> Self) -> Self {
+        if !matches!(
+            self.rightmost_operand(),
+            Self::Primary(PrimaryExpression::Variable(_) | PrimaryExpression::ArrayCreation(_))
+        ) {
+            return assignment(self, value);
+        }
+
+        self.map_rightmost_operand(|operand| assignment(operand, value))
+    }
+
+    fn rightmost_operand(&self) -> &Self {
+        match self {
+            Self::Clone(r)
+            | Self::Pos(r)
+            | Self::Neg(r)
+            | Self::BitwiseNot(r)
+            | Self::ErrorControl(r)
+            | Self::Cast(_, r)
+            | Self::LogicalNot(r)
+            | Self::Power(_, r)
+            | Self::Multiply(_, r)
+            | Self::Divide(_, r)
+            | Self::Modulo(_, r)
+            | Self::Add(_, r)
+            | Self::Sub(_, r)
+            | Self::Concatenate(_, r)
+            | Self::LeftShift(_, r)
+            | Self::RightShift(_, r)
+            | Self::LessThan(_, r)
+            | Self::GreaterThan(_, r)
+            | Self::LessThanOrEqual(_, r)
+            | Self::GreaterThanOrEqual(_, r)
+            | Self::Spaceship(_, r)
+            | Self::Equal(_, r)
+            | Self::NotEqual(_, r)
+            | Self::Inequal(_, r)
+            | Self::StrictEqual(_, r)
+            | Self::StrictNotEqual(_, r)
+            | Self::BitwiseAnd(_, r)
+            | Self::BitwiseExc(_, r)
+            | Self::BitwiseInc(_, r)
+            | Self::And(_, r)
+            | Self::Or(_, r)
+            | Self::Coalesce(_, r)
+            | Self::Conditional(_, _, r) => r.rightmost_operand(),
+            _ => self,
+        }
+    }
+
+    // Replaces the rightmost operand with `f(operand)`, rebuilding the operators around it
+    fn map_rightmost_operand(self, f: impl FnOnce(Self) -> Self) -> Self {
+        match self {
+            Self::Clone(r) => Self::Clone(Box::new(r.map_rightmost_operand(f))),
+            Self::Pos(r) => Self::Pos(Box::new(r.map_rightmost_operand(f))),
+            Self::Neg(r) => Self::Neg(Box::new(r.map_rightmost_operand(f))),
+            Self::BitwiseNot(r) => Self::BitwiseNot(Box::new(r.map_rightmost_operand(f))),
+            Self::ErrorControl(r) => Self::ErrorControl(Box::new(r.map_rightmost_operand(f))),
+            Self::Cast(t, r) => Self::Cast(t, Box::new(r.map_rightmost_operand(f))),
+            Self::LogicalNot(r) => Self::LogicalNot(Box::new(r.map_rightmost_operand(f))),
+            Self::Power(l, r) => Self::Power(l, Box::new(r.map_rightmost_operand(f))),
+            Self::Multiply(l, r) => Self::Multiply(l, Box::new(r.map_rightmost_operand(f))),
+            Self::Divide(l, r) => Self::Divide(l, Box::new(r.map_rightmost_operand(f))),
+            Self::Modulo(l, r) => Self::Modulo(l, Box::new(r.map_rightmost_operand(f))),
+            Self::Add(l, r) => Self::Add(l, Box::new(r.map_rightmost_operand(f))),
+            Self::Sub(l, r) => Self::Sub(l, Box::new(r.map_rightmost_operand(f))),
+            Self::Concatenate(l, r) => Self::Concatenate(l, Box::new(r.map_rightmost_operand(f))),
+            Self::LeftShift(l, r) => Self::LeftShift(l, Box::new(r.map_rightmost_operand(f))),
+            Self::RightShift(l, r) => Self::RightShift(l, Box::new(r.map_rightmost_operand(f))),
+            Self::LessThan(l, r) => Self::LessThan(l, Box::new(r.map_rightmost_operand(f))),
+            Self::GreaterThan(l, r) => Self::GreaterThan(l, Box::new(r.map_rightmost_operand(f))),
+            Self::LessThanOrEqual(l, r) => {
+                Self::LessThanOrEqual(l, Box::new(r.map_rightmost_operand(f)))
+            }
+            Self::GreaterThanOrEqual(l, r) => {
+                Self::GreaterThanOrEqual(l, Box::new(r.map_rightmost_operand(f)))
+            }
+            Self::Spaceship(l, r) => Self::Spaceship(l, Box::new(r.map_rightmost_operand(f))),
+            Self::Equal(l, r) => Self::Equal(l, Box::new(r.map_rightmost_operand(f))),
+            Self::NotEqual(l, r) => Self::NotEqual(l, Box::new(r.map_rightmost_operand(f))),
+            Self::Inequal(l, r) => Self::Inequal(l, Box::new(r.map_rightmost_operand(f))),
+            Self::StrictEqual(l, r) => Self::StrictEqual(l, Box::new(r.map_rightmost_operand(f))),
+            Self::StrictNotEqual(l, r) => {
+                Self::StrictNotEqual(l, Box::new(r.map_rightmost_operand(f)))
+            }
+            Self::BitwiseAnd(l, r) => Self::BitwiseAnd(l, Box::new(r.map_rightmost_operand(f))),
+            Self::BitwiseExc(l, r) => Self::BitwiseExc(l, Box::new(r.map_rightmost_operand(f))),
+            Self::BitwiseInc(l, r) => Self::BitwiseInc(l, Box::new(r.map_rightmost_operand(f))),
+            Self::And(l, r) => Self::And(l, Box::new(r.map_rightmost_operand(f))),
+            Self::Or(l, r) => Self::Or(l, Box::new(r.map_rightmost_operand(f))),
+            Self::Coalesce(l, r) => Self::Coalesce(l, Box::new(r.map_rightmost_operand(f))),
+            Self::Conditional(l, e, r) => {
+                Self::Conditional(l, e, Box::new(r.map_rightmost_operand(f)))
+            }
+            operand => f(operand),
+        }
+    }
+
+    fn is_relational(&self) -> bool {
+        matches!(
+            self,
+            Self::LessThan(_, _)
+                | Self::GreaterThan(_, _)
+                | Self::LessThanOrEqual(_, _)
+                | Self::GreaterThanOrEqual(_, _)
+        )
+    }
+
+    fn is_equality(&self) -> bool {
+        matches!(
+            self,
+            Self::Equal(_, _)
+                | Self::NotEqual(_, _)
+                | Self::Inequal(_, _)
+                | Self::StrictEqual(_, _)
+                | Self::StrictNotEqual(_, _)
+                | Self::Spaceship(_, _)
+        )
+    }
+
+    // NOTE: `$a < $b < $c`, `$a == $b != $c` and `$a ? $b : $c ? $d : $e` are errors in PHP,
+    // only operators binding looser than comparisons can hide such a chain in their operands
+    fn has_non_associative_chain(&self) -> bool {
+        match self {
+            Self::LessThan(l, _)
+            | Self::GreaterThan(l, _)
+            | Self::LessThanOrEqual(l, _)
+            | Self::GreaterThanOrEqual(l, _) => l.is_relational(),
+            Self::Equal(l, _)
+            | Self::NotEqual(l, _)
+            | Self::Inequal(l, _)
+            | Self::StrictEqual(l, _)
+            | Self::StrictNotEqual(l, _)
+            | Self::Spaceship(l, _) => l.is_equality() || l.has_non_associative_chain(),
+            // NOTE: Only short ternaries `$a ?: $b ?: $c` can be chained
+            Self::Conditional(l, e, r) => {
+                matches!(&**l, Self::Conditional(_, le, _) if e.is_some() || le.is_some())
+                    || l.has_non_associative_chain()
+                    || r.has_non_associative_chain()
+            }
+            Self::BitwiseAnd(l, r)
+            | Self::BitwiseExc(l, r)
+            | Self::BitwiseInc(l, r)
+            | Self::And(l, r)
+            | Self::Or(l, r)
+            | Self::Coalesce(l, r)
+            | Self::Assignment(l, r)
+            | Self::CompoundAssignment {
+                target: l,
+                value: r,
+                ..
+            }
+            | Self::LogicalAnd(l, r)
+            | Self::LogicalXor(l, r)
+            | Self::LogicalOr(l, r) => {
+                l.has_non_associative_chain() || r.has_non_associative_chain()
+            }
+            Self::Yield(Some(e))
+            | Self::YieldPair { value: e, .. }
+            | Self::YieldFrom(e)
+            | Self::Print(e) => e.has_non_associative_chain(),
+            _ => false,
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::parser::atoms::relative_scope::RelativeScope;
     use crate::parser::tokenize;
 
     use super::*;
@@ -315,4 +628,405 @@ mod tests {
             Ok(Expression::Assignment(_, _))
         ));
     }
+
+    #[test]
+    fn assignment_chain() {
+        assert!(matches!(
+            parse(r#"$a = $b = 3"#),
+            Ok(Expression::Assignment(_, r)) if matches!(*r, Expression::Assignment(_, _))
+        ));
+    }
+
+    #[test]
+    fn list_assignment() {
+        assert!(matches!(
+            parse(r#"[$a, $b] = [$b, $a]"#),
+            Ok(Expression::ListAssignment(ListIntrinsic::Unkeyed(_), _))
+        ));
+
+        assert!(matches!(
+            parse(r#"list('id' => $id) = $row"#),
+            Ok(Expression::ListAssignment(ListIntrinsic::Keyed(_), _))
+        ));
+
+        assert!(matches!(
+            parse(r#"$x = [, $b] = $pair"#),
+            Ok(Expression::Assignment(_, r)) if matches!(*r, Expression::ListAssignment(_, _))
+        ));
+    }
+
+    #[test]
+    fn list_assignment_precedence() {
+        assert!(matches!(
+            parse(r#"[$a, $b] = foo() or die()"#),
+            Ok(Expression::LogicalOr(l, _)) if matches!(*l, Expression::ListAssignment(_, _))
+        ));
+
+        assert!(matches!(
+            parse(r#"list($a, $b) = foo() or die()"#),
+            Ok(Expression::LogicalOr(l, _)) if matches!(*l, Expression::ListAssignment(_, _))
+        ));
+
+        assert!(matches!(
+            parse(r#"list($a) = $b ? $c : $d"#),
+            Ok(Expression::ListAssignment(_, r)) if matches!(*r, Expression::Conditional(..))
+        ));
+    }
+
+    #[test]
+    fn list_assignment_nested() {
+        let src = format!("{}$a{} = $b", "[".repeat(32), "]".repeat(32));
+        assert!(matches!(
+            parse(&src),
+            Ok(Expression::ListAssignment(ListIntrinsic::Unkeyed(_), _))
+        ));
+    }
+
+    #[test]
+    fn array_creation_is_not_list() {
+        assert!(matches!(
+            parse(r#"[$a, $b]"#),
+            Ok(Expression::Primary(PrimaryExpression::ArrayCreation(_)))
+        ));
+
+        assert!(matches!(
+            parse(r#"$a = []"#),
+            Ok(Expression::Assignment(_, _))
+        ));
+    }
+
+    #[test]
+    fn list_assignment_fail() {
+        assert_eq!(parse(r#"[1, 2] = $a"#), Err(()));
+        assert_eq!(parse(r#"[] = $x"#), Err(()));
+        assert_eq!(parse(r#"[$a, 'k' => $b] = $x"#), Err(()));
+        assert_eq!(parse(r#"$x && [[1]] = $y"#), Err(()));
+        assert_eq!(parse(r#"list() = $x"#), Err(()));
+    }
+
+    #[test]
+    fn assignment_in_unary_operand() {
+        assert!(matches!(
+            parse(r#"!$a = f()"#),
+            Ok(Expression::LogicalNot(e)) if matches!(*e, Expression::Assignment(_, _))
+        ));
+
+        assert!(matches!(
+            parse(r#"@$a = f()"#),
+            Ok(Expression::ErrorControl(e)) if matches!(*e, Expression::Assignment(_, _))
+        ));
+
+        assert!(matches!(
+            parse(r#"(int) $a = f()"#),
+            Ok(Expression::Cast(CastType::Int, e)) if matches!(*e, Expression::Assignment(_, _))
+        ));
+
+        assert!(matches!(
+            parse(r#"-$a = 1 + 2"#),
+            Ok(Expression::Neg(e)) if matches!(*e, Expression::Assignment(_, _))
+        ));
+
+        assert!(matches!(
+            parse(r#"!$a .= 'x'"#),
+            Ok(Expression::LogicalNot(e)) if matches!(*e, Expression::CompoundAssignment { .. })
+        ));
+    }
+
+    #[test]
+    fn assignment_in_binary_operand() {
+        assert!(matches!(
+            parse(r#"$x && $a = $b"#),
+            Ok(Expression::And(_, r)) if matches!(*r, Expression::Assignment(_, _))
+        ));
+
+        assert!(matches!(
+            parse(r#"$x + !$a = $b"#),
+            Ok(Expression::Add(_, r)) if matches!(
+                *r,
+                Expression::LogicalNot(ref e) if matches!(**e, Expression::Assignment(_, _))
+            )
+        ));
+
+        assert!(matches!(
+            parse(r#"$x ?? [$a] = $b"#),
+            Ok(Expression::Coalesce(_, r)) if matches!(*r, Expression::ListAssignment(_, _))
+        ));
+    }
+
+    #[test]
+    fn compound_assignment() {
+        assert!(matches!(
+            parse(r#"$a .= 'x'"#),
+            Ok(Expression::CompoundAssignment {
+                op: CompoundAssignmentOperator::Concatenate,
+                ..
+            })
+        ));
+
+        assert!(matches!(
+            parse(r#"$a ??= $b ?? 3"#),
+            Ok(Expression::CompoundAssignment {
+                op: CompoundAssignmentOperator::Coalesce,
+                value,
+                ..
+            }) if matches!(*value, Expression::Coalesce(_, _))
+        ));
+    }
+
+    #[test]
+    fn compound_assignment_chain() {
+        assert!(matches!(
+            parse(r#"$a += $b -= 1"#),
+            Ok(Expression::CompoundAssignment {
+                op: CompoundAssignmentOperator::Add,
+                value,
+                ..
+            }) if matches!(*value, Expression::CompoundAssignment { op: CompoundAssignmentOperator::Sub, .. })
+        ));
+
+        assert!(matches!(
+            parse(r#"$a = $b **= 2"#),
+            Ok(Expression::Assignment(_, r)) if matches!(*r, Expression::CompoundAssignment { .. })
+        ));
+    }
+
+    #[test]
+    fn cast() {
+        assert!(matches!(
+            parse(r#"(int) $test"#),
+            Ok(Expression::Cast(CastType::Int, _))
+        ));
+
+        assert!(matches!(
+            parse(r#"( Array )$test"#),
+            Ok(Expression::Cast(CastType::Array, _))
+        ));
+
+        assert!(matches!(
+            parse(r#"(string) $a . $b"#),
+            Ok(Expression::Concatenate(l, _)) if matches!(*l, Expression::Cast(CastType::String, _))
+        ));
+    }
+
+    #[test]
+    fn instanceof() {
+        assert!(matches!(
+            parse(r#"$test instanceof Foo"#),
+            Ok(Expression::InstanceOf(_, d)) if matches!(*d, ClassTypeDesignator::QualifiedName(_))
+        ));
+
+        assert!(matches!(
+            parse(r#"$test instanceof $class"#),
+            Ok(Expression::InstanceOf(_, d)) if matches!(*d, ClassTypeDesignator::NewVariable(_))
+        ));
+    }
+
+    #[test]
+    fn instanceof_relative_scope() {
+        assert!(matches!(
+            parse(r#"$other instanceof self"#),
+            Ok(Expression::InstanceOf(_, d)) if matches!(*d, ClassTypeDesignator::RelativeScope(RelativeScope::Self_))
+        ));
+
+        assert!(matches!(
+            parse(r#"$other instanceof static"#),
+            Ok(Expression::InstanceOf(_, d)) if matches!(*d, ClassTypeDesignator::RelativeScope(RelativeScope::Static))
+        ));
+
+        assert!(matches!(
+            parse(r#"$other instanceof parent && $valid"#),
+            Ok(Expression::And(l, _)) if matches!(*l, Expression::InstanceOf(_, _))
+        ));
+    }
+
+    #[test]
+    fn instanceof_precedence() {
+        assert!(matches!(
+            parse(r#"!$test instanceof Foo"#),
+            Ok(Expression::LogicalNot(e)) if matches!(*e, Expression::InstanceOf(_, _))
+        ));
+
+        assert!(matches!(
+            parse(r#"$test instanceof Foo && $valid"#),
+            Ok(Expression::And(l, _)) if matches!(*l, Expression::InstanceOf(_, _))
+        ));
+
+        assert!(matches!(
+            parse(r#"(object) $test instanceof Foo"#),
+            Ok(Expression::InstanceOf(l, _)) if matches!(*l, Expression::Cast(CastType::Object, _))
+        ));
+    }
+
+    #[test]
+    fn yield_() {
+        assert_eq!(parse(r#"yield"#), Ok(Expression::Yield(None)));
+
+        assert!(matches!(
+            parse(r#"yield $value + 1"#),
+            Ok(Expression::Yield(Some(value))) if matches!(*value, Expression::Add(_, _))
+        ));
+
+        assert!(matches!(
+            parse(r#"yield $key => $value + 1"#),
+            Ok(Expression::YieldPair { value, .. }) if matches!(*value, Expression::Add(_, _))
+        ));
+    }
+
+    #[test]
+    fn yield_from() {
+        assert!(matches!(
+            parse(r#"yield from generate()"#),
+            Ok(Expression::YieldFrom(_))
+        ));
+    }
+
+    #[test]
+    fn yield_assignment() {
+        assert!(matches!(
+            parse(r#"$data = yield $value"#),
+            Ok(Expression::Assignment(_, value)) if matches!(*value, Expression::Yield(Some(_)))
+        ));
+
+        assert!(matches!(
+            parse(r#"$data = yield"#),
+            Ok(Expression::Assignment(_, value)) if matches!(*value, Expression::Yield(None))
+        ));
+
+        assert!(matches!(
+            parse(r#"$result = yield from $inner"#),
+            Ok(Expression::Assignment(_, value)) if matches!(*value, Expression::YieldFrom(_))
+        ));
+    }
+
+    #[test]
+    fn divide() {
+        assert!(matches!(
+            parse(r#"$a / $b * $c"#),
+            Ok(Expression::Multiply(l, _)) if matches!(*l, Expression::Divide(_, _))
+        ));
+    }
+
+    #[test]
+    fn power_precedence() {
+        assert!(matches!(
+            parse(r#"-2 ** 2"#),
+            Ok(Expression::Neg(e)) if matches!(*e, Expression::Power(_, _))
+        ));
+
+        assert!(matches!(
+            parse(r#"2 ** 3 ** 2"#),
+            Ok(Expression::Power(_, r)) if matches!(*r, Expression::Power(_, _))
+        ));
+
+        assert!(matches!(
+            parse(r#"2 ** -1"#),
+            Ok(Expression::Power(_, r)) if matches!(*r, Expression::Neg(_))
+        ));
+    }
+
+    #[test]
+    fn concatenate_precedence() {
+        assert!(matches!(
+            parse(r#"$a . $b + $c"#),
+            Ok(Expression::Concatenate(_, r)) if matches!(*r, Expression::Add(_, _))
+        ));
+
+        assert!(matches!(
+            parse(r#"$a << 1 . $b"#),
+            Ok(Expression::Concatenate(l, _)) if matches!(*l, Expression::LeftShift(_, _))
+        ));
+    }
+
+    #[test]
+    fn comparison_precedence() {
+        assert!(matches!(
+            parse(r#"$a == $b < $c"#),
+            Ok(Expression::Equal(_, r)) if matches!(*r, Expression::LessThan(_, _))
+        ));
+
+        assert!(matches!(
+            parse(r#"$a & $b == $c"#),
+            Ok(Expression::BitwiseAnd(_, r)) if matches!(*r, Expression::Equal(_, _))
+        ));
+
+        assert!(matches!(
+            parse(r#"$a < $b . $c"#),
+            Ok(Expression::LessThan(_, r)) if matches!(*r, Expression::Concatenate(_, _))
+        ));
+    }
+
+    #[test]
+    fn non_associative_fail() {
+        assert_eq!(parse(r#"$a < $b < $c"#), Err(()));
+        assert_eq!(parse(r#"$a == $b != $c"#), Err(()));
+        assert_eq!(parse(r#"$a <=> $b === 0"#), Err(()));
+        assert_eq!(parse(r#"$a && $b > $c >= $d"#), Err(()));
+        assert_eq!(parse(r#"$a ? $b : $c ? $d : $e"#), Err(()));
+        assert_eq!(parse(r#"$a ?: $b ? $c : $d"#), Err(()));
+    }
+
+    #[test]
+    fn non_associative_parenthesized() {
+        assert!(matches!(
+            parse(r#"($a < $b) < $c"#),
+            Ok(Expression::LessThan(_, _))
+        ));
+
+        assert!(matches!(
+            parse(r#"$a == ($b != $c)"#),
+            Ok(Expression::Equal(_, _))
+        ));
+
+        assert!(matches!(
+            parse(r#"$a ?: $b ?: $c"#),
+            Ok(Expression::Conditional(l, _, _)) if matches!(*l, Expression::Conditional(_, _, _))
+        ));
+    }
+
+    #[test]
+    fn coalesce_associativity() {
+        assert!(matches!(
+            parse(r#"$a ?? $b ?? $c"#),
+            Ok(Expression::Coalesce(_, r)) if matches!(*r, Expression::Coalesce(_, _))
+        ));
+
+        assert!(matches!(
+            parse(r#"$a ?? $b || $c"#),
+            Ok(Expression::Coalesce(_, r)) if matches!(*r, Expression::Or(_, _))
+        ));
+    }
+
+    #[test]
+    fn logical_keywords() {
+        assert!(matches!(
+            parse(r#"$a = $b and $c"#),
+            Ok(Expression::LogicalAnd(l, _)) if matches!(*l, Expression::Assignment(_, _))
+        ));
+
+        assert!(matches!(
+            parse(r#"$a or $b xor $c and $d"#),
+            Ok(Expression::LogicalOr(_, r)) if matches!(
+                &*r,
+                Expression::LogicalXor(_, r) if matches!(**r, Expression::LogicalAnd(_, _))
+            )
+        ));
+
+        assert!(matches!(
+            parse(r#"$a and $b && $c"#),
+            Ok(Expression::LogicalAnd(_, r)) if matches!(*r, Expression::And(_, _))
+        ));
+    }
+
+    #[test]
+    fn print() {
+        assert!(matches!(
+            parse(r#"print $a . $b"#),
+            Ok(Expression::Print(e)) if matches!(*e, Expression::Concatenate(_, _))
+        ));
+
+        assert!(matches!(
+            parse(r#"print $a or $b"#),
+            Ok(Expression::LogicalOr(l, _)) if matches!(*l, Expression::Print(_))
+        ));
+    }
 }
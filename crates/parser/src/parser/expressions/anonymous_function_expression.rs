@@ -1,5 +1,7 @@
+use crate::parser::atoms::attribute::AttributeGroup;
 use crate::parser::atoms::parameter::parameter_declaration::ParameterDeclaration;
 use crate::parser::atoms::r#type::return_type::ReturnType;
+use crate::parser::keyword;
 use crate::parser::statements::compound_statement::CompoundStatement;
 use crate::parser::statements::Statement;
 use crate::parser::BoxedParser;
@@ -12,6 +14,7 @@ use super::Expression;
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct AnonymousFunctionCreationExpression<'a> {
+    pub attributes: Vec<AttributeGroup<'a>>,
     pub static_: bool,
     pub reference: bool,
     pub parameters: Vec<ParameterDeclaration<'a>>,
@@ -28,7 +31,8 @@ impl<'a> AnonymousFunctionCreationExpression<'a> {
     where
         I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
     {
-        let static_ = just(Token::StaticKeyword).or_not().map(|t| t.is_some());
+        let attributes = AttributeGroup::list_parser(expression_parser.clone());
+        let static_ = keyword(Token::StaticKeyword).or_not().map(|t| t.is_some());
         let reference = just(Token::Ampersand).or_not().map(|t| t.is_some());
         let parameters = just(Token::OpenParen)
             .ignore_then(ParameterDeclaration::list_parser(expression_parser))
@@ -38,14 +42,16 @@ impl<'a> AnonymousFunctionCreationExpression<'a> {
 
         let body = CompoundStatement::parser(statement_parser);
 
-        static_
-            .then_ignore(just(Token::FunctionKeyword))
+        attributes
+            .then(static_)
+            .then_ignore(keyword(Token::FunctionKeyword))
             .then(reference)
             .then(parameters)
             .then(return_type)
             .then(body)
             .map(
-                |((((static_, reference), parameters), return_type), body)| Self {
+                |(((((attributes, static_), reference), parameters), return_type), body)| Self {
+                    attributes,
                     static_,
                     reference,
                     parameters,
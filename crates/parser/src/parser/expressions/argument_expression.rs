@@ -1,3 +1,4 @@
+use crate::parser::atoms::name::Name;
 use crate::parser::BoxedParser;
 use chumsky::{input::ValueInput, span::SimpleSpan};
 use chumsky::{prelude::*, Parser};
@@ -6,10 +7,12 @@ use phprs_lexer::Token;
 
 use super::Expression;
 
+// `$value`, `...$values` or `name: $value`
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct ArgumentExpression<'a> {
-    variadic: bool,
-    expression: Expression<'a>,
+    pub name: Option<Name<'a>>,
+    pub spread: bool,
+    pub expression: Expression<'a>,
 }
 
 impl<'a> ArgumentExpression<'a> {
@@ -19,14 +22,26 @@ impl<'a> ArgumentExpression<'a> {
     where
         I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
     {
-        let variadic = just(Token::DotDotDot).or_not().map(|a| a.is_some());
+        let named = Name::identifier_parser()
+            .then_ignore(just(Token::Colon))
+            .then(expression_parser.clone())
+            .map(|(name, expression)| Self {
+                name: Some(name),
+                spread: false,
+                expression,
+            });
+
+        let spread = just(Token::DotDotDot).or_not().map(|a| a.is_some());
 
-        variadic
+        let positional = spread
             .then(expression_parser)
-            .map(|(variadic, expression)| Self {
-                variadic,
+            .map(|(spread, expression)| Self {
+                name: None,
+                spread,
                 expression,
-            })
+            });
+
+        choice((named, positional)).labelled("ArgumentExpression")
     }
 
     pub fn list_parser<I>(
@@ -36,7 +51,186 @@ impl<'a> ArgumentExpression<'a> {
         I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
     {
         Self::parser(expression_parser)
+            .map_with(|argument, e| (argument, e.span()))
             .separated_by(just(Token::Comma))
-            .collect()
+            .allow_trailing()
+            .collect::<Vec<_>>()
+            .validate(|arguments, _, emitter| {
+                let mut named = false;
+                let mut unpacked = false;
+
+                for (argument, span) in &arguments {
+                    if argument.name.is_some() {
+                        named = true;
+                    } else if argument.spread {
+                        if named {
+                            emitter.emit(Rich::custom(
+                                *span,
+                                "Cannot use argument unpacking after named arguments",
+                            ));
+                        }
+                        unpacked = true;
+                    } else if named {
+                        emitter.emit(Rich::custom(
+                            *span,
+                            "Cannot use positional argument after named argument",
+                        ));
+                    } else if unpacked {
+                        emitter.emit(Rich::custom(
+                            *span,
+                            "Cannot use positional argument after argument unpacking",
+                        ));
+                    }
+                }
+
+                arguments
+                    .into_iter()
+                    .map(|(argument, _)| argument)
+                    .collect()
+            })
+            .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::{statements::Statement, tokenize};
+
+    use super::*;
+
+    fn parse(src: &str) -> Result<Vec<ArgumentExpression<'_>>, ()> {
+        let tokens = tokenize(src);
+
+        ArgumentExpression::list_parser(Expression::parser(Statement::parser().boxed()))
+            .parse(tokens)
+            .into_result()
+            .map_err(|_| ())
+    }
+
+    #[test]
+    fn positional() {
+        let res = parse(r#"$a, 5"#).unwrap();
+        assert!(matches!(
+            &res[..],
+            [
+                ArgumentExpression {
+                    name: None,
+                    spread: false,
+                    ..
+                },
+                ArgumentExpression { name: None, .. }
+            ]
+        ));
+    }
+
+    #[test]
+    fn named() {
+        let res = parse(r#"'x', name: 'y', value: $z"#).unwrap();
+        assert!(matches!(
+            &res[..],
+            [
+                ArgumentExpression { name: None, .. },
+                ArgumentExpression {
+                    name: Some(Name("name")),
+                    ..
+                },
+                ArgumentExpression {
+                    name: Some(Name("value")),
+                    ..
+                },
+            ]
+        ));
+    }
+
+    #[test]
+    fn spread() {
+        let res = parse(r#"$a, ...$rest"#).unwrap();
+        assert!(matches!(
+            &res[..],
+            [
+                ArgumentExpression { spread: false, .. },
+                ArgumentExpression { spread: true, .. }
+            ]
+        ));
+    }
+
+    #[test]
+    fn trailing_comma() {
+        let res = parse(r#"$a, $b,"#).unwrap();
+        assert_eq!(res.len(), 2);
+    }
+
+    #[test]
+    fn constant() {
+        let res = parse(r#"PHP_EOL"#).unwrap();
+        assert!(matches!(&res[..], [ArgumentExpression { name: None, .. }]));
+    }
+
+    #[test]
+    fn positional_after_named_fail() {
+        let res = parse(r#"name: 'x', $y"#);
+        assert_eq!(res, Err(()));
+    }
+
+    #[test]
+    fn spread_after_named_fail() {
+        let res = parse(r#"name: 'x', ...$rest"#);
+        assert_eq!(res, Err(()));
+    }
+
+    #[test]
+    fn positional_after_spread_fail() {
+        let res = parse(r#"...$rest, $y"#);
+        assert_eq!(res, Err(()));
+    }
+
+    #[test]
+    fn named_after_spread() {
+        let res = parse(r#"...$a, ...$b, name: 'x'"#).unwrap();
+        assert!(matches!(
+            &res[..],
+            [
+                ArgumentExpression { spread: true, .. },
+                ArgumentExpression { spread: true, .. },
+                ArgumentExpression { name: Some(_), .. }
+            ]
+        ));
+    }
+
+    #[test]
+    fn keyword_named() {
+        let res = parse(r#"array: $x, class: 1, default: null"#).unwrap();
+        assert!(matches!(
+            &res[..],
+            [
+                ArgumentExpression {
+                    name: Some(Name("array")),
+                    ..
+                },
+                ArgumentExpression {
+                    name: Some(Name("class")),
+                    ..
+                },
+                ArgumentExpression {
+                    name: Some(Name("default")),
+                    ..
+                },
+            ]
+        ));
+
+        let res = parse(r#"Default: null"#).unwrap();
+        assert!(matches!(
+            &res[..],
+            [ArgumentExpression {
+                name: Some(Name("Default")),
+                ..
+            }]
+        ));
+    }
+
+    #[test]
+    fn single_comma_fail() {
+        let res = parse(r#","#);
+        assert_eq!(res, Err(()));
     }
 }
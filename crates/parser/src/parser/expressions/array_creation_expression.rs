@@ -3,15 +3,16 @@ use chumsky::{prelude::*, Parser};
 
 use phprs_lexer::Token;
 
+use crate::parser::keyword;
 use crate::parser::BoxedParser;
 
 use super::Expression;
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct ArrayElement<'a> {
-    key: Option<Expression<'a>>,
-    value: Expression<'a>,
-    reference: bool,
+    pub key: Option<Expression<'a>>,
+    pub value: Expression<'a>,
+    pub reference: bool,
 }
 
 impl<'a> ArrayElement<'a> {
@@ -22,35 +23,45 @@ impl<'a> ArrayElement<'a> {
     where
         I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
     {
-        let value = just(Token::Ampersand)
-            .or_not()
-            .map(|t| t.is_some())
-            .then(expression_parser.clone());
+        let reference = just(Token::Ampersand).or_not().map(|t| t.is_some());
 
-        let with_key = expression_parser
-            .clone()
-            .then_ignore(just(Token::DoubleArrow))
-            .then(value.clone())
-            .map(|(key, (reference, value))| Self {
-                key: Some(key),
+        let by_reference = just(Token::Ampersand)
+            .ignore_then(expression_parser.clone())
+            .map(|value| Self {
+                key: None,
                 value,
-                reference,
+                reference: true,
             });
 
-        choice((
-            with_key,
-            value.map(|(reference, value)| Self {
-                key: None,
-                reference,
-                value,
-            }),
-        ))
+        // NOTE: The key is parsed as a value first so nested arrays are not parsed twice
+        let with_optional_key = expression_parser
+            .clone()
+            .then(
+                just(Token::DoubleArrow)
+                    .ignore_then(reference.then(expression_parser))
+                    .or_not(),
+            )
+            .map(|(first, rest)| match rest {
+                Some((reference, value)) => Self {
+                    key: Some(first),
+                    value,
+                    reference,
+                },
+                None => Self {
+                    key: None,
+                    value: first,
+                    reference: false,
+                },
+            });
+
+        choice((by_reference, with_optional_key))
     }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct ArrayCreationExpression<'a> {
-    elements: Vec<ArrayElement<'a>>,
+    // NOTE: Skipped elements (`[, $b]`) are `None`, they are only valid once converted to a list
+    pub elements: Vec<Option<ArrayElement<'a>>>,
 }
 
 impl<'a> ArrayCreationExpression<'a> {
@@ -61,15 +72,32 @@ impl<'a> ArrayCreationExpression<'a> {
         I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
     {
         let elements = ArrayElement::parser(expression_parser)
+            .or_not()
             .separated_by(just(Token::Comma))
-            .allow_trailing()
-            .collect()
-            .map(|elements| Self { elements });
+            .collect::<Vec<_>>()
+            .map(|mut elements| {
+                // NOTE: A trailing comma is not a skipped element
+                if let Some(None) = elements.last() {
+                    elements.pop();
+                }
+
+                Self { elements }
+            });
 
-        let with_keyword = just(Token::ArrayKeyword)
+        let with_keyword = keyword(Token::ArrayKeyword)
             .ignore_then(just(Token::OpenParen))
             .ignore_then(elements.clone())
-            .then_ignore(just(Token::CloseParen));
+            .then_ignore(just(Token::CloseParen))
+            .validate(|array: Self, e, emitter| {
+                if array.elements.iter().any(Option::is_none) {
+                    emitter.emit(Rich::custom(
+                        e.span(),
+                        "Cannot use empty array elements in arrays",
+                    ));
+                }
+
+                array
+            });
 
         let without_keyword = just(Token::OpenBracket)
             .ignore_then(elements)
@@ -78,3 +106,42 @@ impl<'a> ArrayCreationExpression<'a> {
         choice((with_keyword, without_keyword))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::{statements::Statement, tokenize};
+
+    use super::*;
+
+    fn parse(src: &str) -> Result<ArrayCreationExpression<'_>, ()> {
+        let tokens = tokenize(src);
+
+        ArrayCreationExpression::parser(Expression::parser(Statement::parser().boxed()))
+            .parse(tokens)
+            .into_result()
+            .map_err(|_| ())
+    }
+
+    #[test]
+    fn elements() {
+        assert!(matches!(parse(r#"[]"#), Ok(array) if array.elements.is_empty()));
+        assert!(matches!(parse(r#"array(1, 2,)"#), Ok(array) if array.elements.len() == 2));
+        assert!(matches!(
+            parse(r#"['a' => &$a]"#),
+            Ok(array) if matches!(array.elements[..], [Some(ArrayElement { key: Some(_), reference: true, .. })])
+        ));
+    }
+
+    #[test]
+    fn skipped() {
+        assert!(matches!(
+            parse(r#"[, $b, ,]"#),
+            Ok(array) if matches!(array.elements[..], [None, Some(_), None])
+        ));
+    }
+
+    #[test]
+    fn skipped_with_keyword_fail() {
+        assert_eq!(parse(r#"array(, $b)"#), Err(()));
+    }
+}
@@ -0,0 +1,131 @@
+use crate::parser::atoms::attribute::AttributeGroup;
+use crate::parser::atoms::parameter::parameter_declaration::ParameterDeclaration;
+use crate::parser::atoms::r#type::return_type::ReturnType;
+use crate::parser::keyword;
+use crate::parser::BoxedParser;
+use chumsky::{input::ValueInput, span::SimpleSpan};
+use chumsky::{prelude::*, Parser};
+
+use phprs_lexer::Token;
+
+use super::Expression;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ArrowFunctionCreationExpression<'a> {
+    pub attributes: Vec<AttributeGroup<'a>>,
+    pub static_: bool,
+    pub reference: bool,
+    pub parameters: Vec<ParameterDeclaration<'a>>,
+    pub return_type: Option<ReturnType<'a>>,
+    pub body: Expression<'a>,
+}
+
+impl<'a> ArrowFunctionCreationExpression<'a> {
+    pub fn parser<I>(
+        expression_parser: BoxedParser<'a, I, Expression<'a>>,
+    ) -> impl Parser<'a, I, Self, extra::Err<Rich<'a, Token<'a>>>> + Clone
+    where
+        I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
+    {
+        let attributes = AttributeGroup::list_parser(expression_parser.clone());
+        let static_ = keyword(Token::StaticKeyword).or_not().map(|t| t.is_some());
+        let reference = just(Token::Ampersand).or_not().map(|t| t.is_some());
+        let parameters = just(Token::OpenParen)
+            .ignore_then(ParameterDeclaration::list_parser(expression_parser.clone()))
+            .then_ignore(just(Token::CloseParen));
+        let return_type = ReturnType::parser().or_not();
+
+        let body = just(Token::DoubleArrow).ignore_then(expression_parser);
+
+        attributes
+            .then(static_)
+            .then_ignore(keyword(Token::FnKeyword))
+            .then(reference)
+            .then(parameters)
+            .then(return_type)
+            .then(body)
+            .map(
+                |(((((attributes, static_), reference), parameters), return_type), body)| Self {
+                    attributes,
+                    static_,
+                    reference,
+                    parameters,
+                    return_type,
+                    body,
+                },
+            )
+            .labelled("ArrowFunctionExpression")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::{statements::Statement, tokenize};
+
+    use super::*;
+
+    fn parse(src: &str) -> Result<ArrowFunctionCreationExpression<'_>, ()> {
+        let token_stream = tokenize(src);
+
+        ArrowFunctionCreationExpression::parser(Expression::parser(Statement::parser().boxed()))
+            .parse(token_stream)
+            .into_result()
+            .map_err(|_| ())
+    }
+
+    #[test]
+    fn simple() {
+        let res = parse(r#"fn($x) => $x * 2"#);
+        assert!(matches!(
+            res,
+            Ok(ArrowFunctionCreationExpression {
+                static_: false,
+                reference: false,
+                return_type: None,
+                body: Expression::Multiply(_, _),
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn static_reference() {
+        let res = parse(r#"static fn &(array $x): ?array => $x"#);
+        assert!(matches!(
+            res,
+            Ok(ArrowFunctionCreationExpression {
+                static_: true,
+                reference: true,
+                return_type: Some(_),
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn nested() {
+        let res = parse(r#"fn($x) => fn($y) => $x + $y"#);
+        assert!(matches!(
+            res,
+            Ok(ArrowFunctionCreationExpression {
+                body: Expression::Primary(_),
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn block_body_fail() {
+        let res = parse(r#"fn($x) { return $x; }"#);
+        assert_eq!(res, Err(()));
+    }
+
+    #[test]
+    fn attributes() {
+        let res = parse(r#"#[Pure] static fn($x) => $x"#);
+        assert!(matches!(
+            res,
+            Ok(ArrowFunctionCreationExpression { attributes, static_: true, .. }) if attributes.len() == 1
+        ));
+    }
+}
@@ -0,0 +1,98 @@
+use chumsky::prelude::*;
+use chumsky::{error::Rich, extra, input::ValueInput, Parser};
+
+use crate::parser::atoms::member_name::MemberName;
+use crate::parser::atoms::name::qualified_name::QualifiedName;
+use crate::parser::atoms::scope_resolution_qualifier::ScopeResolutionQualifier;
+use crate::parser::BoxedParser;
+use phprs_lexer::Token;
+
+use super::Expression;
+
+// First-class callable syntax, `strlen(...)` or `Foo::bar(...)`
+// NOTE: `$fn(...)` and `$object->method(...)` are handled by the Variable postfix chain
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CallableCreationExpression<'a> {
+    Function {
+        name: QualifiedName<'a>,
+    },
+    Scoped {
+        scope: ScopeResolutionQualifier<'a>,
+        member: MemberName<'a>,
+    },
+}
+
+impl<'a> CallableCreationExpression<'a> {
+    pub fn parser<I>(
+        expression_parser: BoxedParser<'a, I, Expression<'a>>,
+    ) -> impl Parser<'a, I, Self, extra::Err<Rich<'a, Token<'a>>>> + Clone
+    where
+        I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
+    {
+        let function = QualifiedName::parser().map(|name| Self::Function { name });
+
+        let scoped = ScopeResolutionQualifier::parser()
+            .then_ignore(just(Token::ColonColon))
+            .then(MemberName::parser(expression_parser))
+            .map(|(scope, member)| Self::Scoped { scope, member });
+
+        choice((scoped, function))
+            .then_ignore(Self::arguments_parser())
+            .labelled("CallableCreationExpression")
+    }
+
+    // `(...)`
+    pub fn arguments_parser<I>() -> impl Parser<'a, I, (), extra::Err<Rich<'a, Token<'a>>>> + Clone
+    where
+        I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
+    {
+        just(Token::OpenParen)
+            .then(just(Token::DotDotDot))
+            .then(just(Token::CloseParen))
+            .ignored()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::atoms::name::Name;
+    use crate::parser::{statements::Statement, tokenize};
+
+    use super::*;
+
+    fn parse(src: &str) -> Result<CallableCreationExpression<'_>, ()> {
+        let tokens = tokenize(src);
+
+        CallableCreationExpression::parser(Expression::parser(Statement::parser().boxed()))
+            .parse(tokens)
+            .into_result()
+            .map_err(|_| ())
+    }
+
+    #[test]
+    fn function() {
+        let res = parse(r#"strlen(...)"#);
+        assert!(matches!(
+            res,
+            Ok(CallableCreationExpression::Function { .. })
+        ));
+    }
+
+    #[test]
+    fn scoped() {
+        let res = parse(r#"Foo::bar(...)"#);
+        assert!(matches!(
+            res,
+            Ok(CallableCreationExpression::Scoped {
+                member: MemberName::Name(Name("bar")),
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn arguments_fail() {
+        let res = parse(r#"strlen(...$args)"#);
+        assert_eq!(res, Err(()));
+    }
+}
@@ -1,4 +1,5 @@
 use crate::parser::atoms::{name::Name, scope_resolution_qualifier::ScopeResolutionQualifier};
+use crate::parser::keyword;
 use chumsky::{input::ValueInput, prelude::*};
 use phprs_lexer::Token;
 
@@ -23,14 +24,64 @@ impl<'a> ClassConstantAccessExpression<'a> {
     {
         let name = ScopeResolutionQualifier::parser()
             .then_ignore(just(Token::ColonColon))
-            .then(Name::parser())
+            .then(Name::identifier_parser())
             .map(|(scope, name)| Self::Name { scope, name });
 
         let class = ScopeResolutionQualifier::parser()
             .then_ignore(just(Token::ColonColon))
-            .then_ignore(just(Token::ClassKeyword))
+            .then_ignore(keyword(Token::ClassKeyword))
             .map(|scope| Self::Class { scope });
 
-        choice((name, class)).labelled("ClassConstantAccessExpression")
+        // NOTE: Before `name` so `Foo::class` is not taken as a constant
+        choice((class, name)).labelled("ClassConstantAccessExpression")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::tokenize;
+
+    use super::*;
+
+    fn parse(src: &str) -> Result<ClassConstantAccessExpression<'_>, ()> {
+        let tokens = tokenize(src);
+
+        ClassConstantAccessExpression::parser()
+            .parse(tokens)
+            .into_result()
+            .map_err(|_| ())
+    }
+
+    #[test]
+    fn name() {
+        let res = parse(r#"Status::Active"#);
+        assert!(matches!(
+            res,
+            Ok(ClassConstantAccessExpression::Name {
+                name: Name("Active"),
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn keyword_name() {
+        let res = parse(r#"Status::DEFAULT"#);
+        assert!(matches!(
+            res,
+            Ok(ClassConstantAccessExpression::Name {
+                name: Name("DEFAULT"),
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn class() {
+        let res = parse(r#"static::CLASS"#);
+        assert!(matches!(
+            res,
+            Ok(ClassConstantAccessExpression::Class { .. })
+        ));
     }
 }
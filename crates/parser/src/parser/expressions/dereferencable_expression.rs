@@ -5,6 +5,7 @@ use chumsky::{input::ValueInput, prelude::*};
 use phprs_lexer::Token;
 
 use super::array_creation_expression::ArrayCreationExpression;
+use super::object_creation_expression::ObjectCreationExpression;
 use super::Expression;
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
@@ -14,6 +15,8 @@ pub enum DereferencableExpression<'a> {
     Expression(Expression<'a>),
     ArrayCreation(ArrayCreationExpression<'a>),
     StringLiteral(StringLiteral<'a>),
+    // NOTE: `new Foo()->bar()`, built by PrimaryExpression
+    ObjectCreation(Box<ObjectCreationExpression<'a>>),
 }
 
 impl<'a> DereferencableExpression<'a> {
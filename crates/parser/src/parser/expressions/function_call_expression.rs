@@ -0,0 +1,78 @@
+use chumsky::prelude::*;
+use chumsky::{error::Rich, extra, input::ValueInput, Parser};
+
+use crate::parser::atoms::name::qualified_name::QualifiedName;
+use crate::parser::BoxedParser;
+use phprs_lexer::Token;
+
+use super::argument_expression::ArgumentExpression;
+use super::Expression;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FunctionCallExpression<'a> {
+    pub name: QualifiedName<'a>,
+    pub arguments: Vec<ArgumentExpression<'a>>,
+}
+
+impl<'a> FunctionCallExpression<'a> {
+    // NOTE: Calls on a callable expression (`$fn()`) are handled by the Variable postfix chain
+    pub fn parser<I>(
+        expression_parser: BoxedParser<'a, I, Expression<'a>>,
+    ) -> impl Parser<'a, I, Self, extra::Err<Rich<'a, Token<'a>>>> + Clone
+    where
+        I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
+    {
+        QualifiedName::parser()
+            .then_ignore(just(Token::OpenParen))
+            .then(ArgumentExpression::list_parser(expression_parser))
+            .then_ignore(just(Token::CloseParen))
+            .map(|(name, arguments)| Self { name, arguments })
+            .labelled("FunctionCallExpression")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::{statements::Statement, tokenize};
+
+    use super::*;
+
+    fn parse(src: &str) -> Result<FunctionCallExpression<'_>, ()> {
+        let tokens = tokenize(src);
+
+        FunctionCallExpression::parser(Expression::parser(Statement::parser().boxed()))
+            .parse(tokens)
+            .into_result()
+            .map_err(|_| ())
+    }
+
+    #[test]
+    fn empty() {
+        let res = parse(r#"test()"#);
+        assert!(
+            matches!(res, Ok(FunctionCallExpression { arguments, .. }) if arguments.is_empty())
+        );
+    }
+
+    #[test]
+    fn arguments() {
+        let res = parse(r#"array_map($test, 5)"#);
+        assert!(
+            matches!(res, Ok(FunctionCallExpression { arguments, .. }) if arguments.len() == 2)
+        );
+    }
+
+    #[test]
+    fn named_arguments() {
+        let res = parse(r#"str_pad($value, length: 10, pad_string: '0',)"#);
+        assert!(
+            matches!(res, Ok(FunctionCallExpression { arguments, .. }) if arguments.len() == 3)
+        );
+    }
+
+    #[test]
+    fn no_parens_fail() {
+        let res = parse(r#"test"#);
+        assert_eq!(res, Err(()));
+    }
+}
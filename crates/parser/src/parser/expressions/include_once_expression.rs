@@ -1,3 +1,4 @@
+use crate::parser::keyword;
 use crate::parser::BoxedParser;
 use chumsky::{input::ValueInput, prelude::*};
 use phprs_lexer::Token;
@@ -16,7 +17,7 @@ impl<'a> IncludeOnceExpression<'a> {
     where
         I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
     {
-        just(Token::IncludeOnceKeyword)
+        keyword(Token::IncludeOnceKeyword)
             .ignore_then(expression_parser)
             .map(|expression| Self { expression })
             .labelled("IncludeOnce expression")
@@ -0,0 +1,326 @@
+use chumsky::prelude::*;
+use chumsky::{error::Rich, extra, input::ValueInput, Parser};
+
+use crate::parser::atoms::literal::integer_literal::IntegerLiteral;
+use crate::parser::atoms::literal::string_literal::StringLiteralKind;
+use crate::parser::atoms::name::variable_name::VariableName;
+use crate::parser::atoms::name::Name;
+use crate::parser::BoxedParser;
+use phprs_lexer::Token;
+
+use super::Expression;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum StringOffset<'a> {
+    Name(Name<'a>),
+    Variable(VariableName<'a>),
+    Integer {
+        negative: bool,
+        integer: IntegerLiteral<'a>,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum InterpolatedStringPart<'a> {
+    // NOTE: Escape sequences are kept as written. In heredocs the closing marker indentation
+    // is removed, which splits literals spanning several lines into one part per line
+    Literal(&'a str),
+    // "$name"
+    Variable(VariableName<'a>),
+    // "$name[offset]"
+    OffsetAccess(VariableName<'a>, StringOffset<'a>),
+    // "$name->property"
+    PropertyAccess(VariableName<'a>, Name<'a>),
+    // "{$expression}"
+    Expression(Expression<'a>),
+    // "${name}"
+    StringVarname(&'a str),
+    // "${expression}"
+    VariableExpression(Expression<'a>),
+}
+
+impl<'a> InterpolatedStringPart<'a> {
+    pub fn list_parser<I>(
+        expression_parser: BoxedParser<'a, I, Expression<'a>>,
+    ) -> impl Parser<'a, I, Vec<Self>, extra::Err<Rich<'a, Token<'a>>>> + Clone
+    where
+        I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
+    {
+        let literal = select! {
+            Token::EncapsedAndWhitespace(text) => Self::Literal(text),
+        };
+
+        let offset = choice((
+            Name::parser().map(StringOffset::Name),
+            VariableName::parser().map(StringOffset::Variable),
+            just(Token::Minus)
+                .or_not()
+                .then(IntegerLiteral::parser())
+                .map(|(minus, integer)| StringOffset::Integer {
+                    negative: minus.is_some(),
+                    integer,
+                }),
+        ))
+        .delimited_by(just(Token::OpenBracket), just(Token::CloseBracket));
+
+        let offset_access = VariableName::parser()
+            .then(offset)
+            .map(|(name, offset)| Self::OffsetAccess(name, offset));
+
+        let property_access = VariableName::parser()
+            .then_ignore(just(Token::Arrow))
+            .then(Name::parser())
+            .map(|(name, property)| Self::PropertyAccess(name, property));
+
+        let variable = VariableName::parser().map(Self::Variable);
+
+        let expression = expression_parser
+            .clone()
+            .delimited_by(just(Token::OpenBraceDollar), just(Token::CloseBrace))
+            .map(Self::Expression);
+
+        let string_varname = select! {
+            Token::StringVarname(name) => Self::StringVarname(name),
+        }
+        .delimited_by(just(Token::DollarOpenBrace), just(Token::CloseBrace));
+
+        let variable_expression = expression_parser
+            .delimited_by(just(Token::DollarOpenBrace), just(Token::CloseBrace))
+            .map(Self::VariableExpression);
+
+        choice((
+            literal,
+            offset_access,
+            property_access,
+            variable,
+            expression,
+            string_varname,
+            variable_expression,
+        ))
+        .repeated()
+        .collect()
+    }
+
+    fn strip_indentation(parts: Vec<Self>, indentation: usize) -> Vec<Self> {
+        let mut stripped = Vec::with_capacity(parts.len());
+        let mut line_start = true;
+
+        for part in parts {
+            let Self::Literal(text) = part else {
+                line_start = false;
+                stripped.push(part);
+                continue;
+            };
+
+            for line in text.split_inclusive('\n') {
+                let line = match line_start {
+                    true => {
+                        let whitespace = line.len() - line.trim_start_matches([' ', '\t']).len();
+                        &line[whitespace.min(indentation)..]
+                    }
+                    false => line,
+                };
+
+                if !line.is_empty() {
+                    stripped.push(Self::Literal(line));
+                }
+                line_start = line.ends_with('\n');
+            }
+        }
+
+        stripped
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct InterpolatedStringExpression<'a> {
+    pub kind: StringLiteralKind,
+    pub parts: Vec<InterpolatedStringPart<'a>>,
+}
+
+impl<'a> InterpolatedStringExpression<'a> {
+    pub fn parser<I>(
+        expression_parser: BoxedParser<'a, I, Expression<'a>>,
+    ) -> impl Parser<'a, I, Self, extra::Err<Rich<'a, Token<'a>>>> + Clone
+    where
+        I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
+    {
+        let parts = InterpolatedStringPart::list_parser(expression_parser);
+
+        let double_quoted = parts
+            .clone()
+            .delimited_by(just(Token::DoubleQuote), just(Token::DoubleQuote))
+            .map(|parts| Self {
+                kind: StringLiteralKind::DoubleQuoted,
+                parts,
+            });
+
+        let heredoc_end = select! {
+            Token::HeredocEnd(marker) => marker,
+        };
+
+        let heredoc = just(Token::HeredocStart)
+            .ignore_then(parts)
+            .then(heredoc_end)
+            .map(|(parts, marker)| {
+                let marker = marker.trim_start_matches(['\r', '\n']);
+                let indentation = marker.len() - marker.trim_start_matches([' ', '\t']).len();
+
+                Self {
+                    kind: StringLiteralKind::Heredoc,
+                    parts: InterpolatedStringPart::strip_indentation(parts, indentation),
+                }
+            });
+
+        choice((double_quoted, heredoc)).labelled("InterpolatedStringExpression")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::{statements::Statement, tokenize};
+
+    use super::*;
+
+    fn parse(src: &str) -> Result<InterpolatedStringExpression<'_>, ()> {
+        let tokens = tokenize(src);
+
+        InterpolatedStringExpression::parser(Expression::parser(Statement::parser().boxed()))
+            .parse(tokens)
+            .into_result()
+            .map_err(|_| ())
+    }
+
+    #[test]
+    fn simple_variable() {
+        let res = parse(r#""Hello $name!""#);
+        assert_eq!(
+            res,
+            Ok(InterpolatedStringExpression {
+                kind: StringLiteralKind::DoubleQuoted,
+                parts: vec![
+                    InterpolatedStringPart::Literal("Hello "),
+                    InterpolatedStringPart::Variable(VariableName("$name")),
+                    InterpolatedStringPart::Literal("!"),
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn offset_access() {
+        let res = parse(r#""$a[0] $a[-1] $a[key] $a[$i]""#).unwrap();
+        assert!(matches!(
+            res.parts[..],
+            [
+                InterpolatedStringPart::OffsetAccess(
+                    _,
+                    StringOffset::Integer {
+                        negative: false,
+                        ..
+                    }
+                ),
+                _,
+                InterpolatedStringPart::OffsetAccess(
+                    _,
+                    StringOffset::Integer { negative: true, .. }
+                ),
+                _,
+                InterpolatedStringPart::OffsetAccess(_, StringOffset::Name(Name("key"))),
+                _,
+                InterpolatedStringPart::OffsetAccess(_, StringOffset::Variable(VariableName("$i"))),
+            ]
+        ));
+    }
+
+    #[test]
+    fn property_access() {
+        let res = parse(r#""$user->name""#);
+        assert!(matches!(
+            res.unwrap().parts[..],
+            [InterpolatedStringPart::PropertyAccess(
+                VariableName("$user"),
+                Name("name")
+            )]
+        ));
+    }
+
+    #[test]
+    fn braced_expression() {
+        let res = parse(r#""Hello {$user->name}, you have $count items""#).unwrap();
+        assert!(matches!(
+            res.parts[..],
+            [
+                InterpolatedStringPart::Literal("Hello "),
+                InterpolatedStringPart::Expression(_),
+                InterpolatedStringPart::Literal(", you have "),
+                InterpolatedStringPart::Variable(VariableName("$count")),
+                InterpolatedStringPart::Literal(" items"),
+            ]
+        ));
+    }
+
+    #[test]
+    fn dollar_brace() {
+        let res = parse(r#""${name} ${$name}""#).unwrap();
+        assert!(matches!(
+            res.parts[..],
+            [
+                InterpolatedStringPart::StringVarname("name"),
+                InterpolatedStringPart::Literal(" "),
+                InterpolatedStringPart::VariableExpression(_),
+            ]
+        ));
+    }
+
+    #[test]
+    fn nested_string() {
+        let res = parse(r#""{$a->get("key $b")}""#).unwrap();
+        assert!(matches!(
+            res.parts[..],
+            [InterpolatedStringPart::Expression(_)]
+        ));
+    }
+
+    #[test]
+    fn heredoc() {
+        let res = parse("<<<EOT\n  Hello $name\n  EOT").unwrap();
+        assert_eq!(res.kind, StringLiteralKind::Heredoc);
+        assert!(matches!(
+            res.parts[..],
+            [
+                InterpolatedStringPart::Literal("Hello "),
+                InterpolatedStringPart::Variable(VariableName("$name")),
+            ]
+        ));
+    }
+
+    #[test]
+    fn heredoc_indentation() {
+        let res = parse(
+            "<<<EOT
+    Hello $name,
+
+      welcome {$site}
+    EOT",
+        )
+        .unwrap();
+        assert!(matches!(
+            res.parts[..],
+            [
+                InterpolatedStringPart::Literal("Hello "),
+                InterpolatedStringPart::Variable(VariableName("$name")),
+                InterpolatedStringPart::Literal(",\n"),
+                InterpolatedStringPart::Literal("\n"),
+                InterpolatedStringPart::Literal("  welcome "),
+                InterpolatedStringPart::Expression(_),
+            ]
+        ));
+    }
+
+    #[test]
+    fn without_interpolation_fail() {
+        let res = parse(r#""Hello""#);
+        assert_eq!(res, Err(()));
+    }
+}
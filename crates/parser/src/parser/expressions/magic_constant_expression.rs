@@ -0,0 +1,67 @@
+use chumsky::prelude::*;
+use chumsky::{error::Rich, extra, input::ValueInput, Parser};
+
+use phprs_lexer::Token;
+
+// `__LINE__`, `__CLASS__`, ...
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MagicConstantExpression {
+    Line,
+    File,
+    Dir,
+    Class,
+    Function,
+    Method,
+    Namespace,
+    Trait,
+}
+
+impl<'a> MagicConstantExpression {
+    pub fn parser<I>() -> impl Parser<'a, I, Self, extra::Err<Rich<'a, Token<'a>>>> + Clone
+    where
+        I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
+    {
+        select! {
+            Token::LineMagicConstant => Self::Line,
+            Token::FileMagicConstant => Self::File,
+            Token::DirMagicConstant => Self::Dir,
+            Token::ClassMagicConstant => Self::Class,
+            Token::FunctionMagicConstant => Self::Function,
+            Token::MethodMagicConstant => Self::Method,
+            Token::NamespaceMagicConstant => Self::Namespace,
+            Token::TraitMagicConstant => Self::Trait,
+        }
+        .labelled("MagicConstantExpression")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::tokenize;
+
+    use super::*;
+
+    fn parse(src: &str) -> Result<MagicConstantExpression, ()> {
+        let tokens = tokenize(src);
+
+        MagicConstantExpression::parser()
+            .parse(tokens)
+            .into_result()
+            .map_err(|_| ())
+    }
+
+    #[test]
+    fn simple() {
+        assert_eq!(parse(r#"__LINE__"#), Ok(MagicConstantExpression::Line));
+        assert_eq!(parse(r#"__DIR__"#), Ok(MagicConstantExpression::Dir));
+        assert_eq!(
+            parse(r#"__NAMESPACE__"#),
+            Ok(MagicConstantExpression::Namespace)
+        );
+    }
+
+    #[test]
+    fn case_insensitive() {
+        assert_eq!(parse(r#"__class__"#), Ok(MagicConstantExpression::Class));
+    }
+}
@@ -0,0 +1,168 @@
+use chumsky::prelude::*;
+use chumsky::{error::Rich, extra, input::ValueInput, Parser};
+
+use crate::parser::keyword;
+use crate::parser::BoxedParser;
+use phprs_lexer::Token;
+
+use super::Expression;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MatchConditions<'a> {
+    Default,
+    Expressions(Vec<Expression<'a>>),
+}
+
+impl<'a> MatchConditions<'a> {
+    pub fn parser<I>(
+        expression_parser: BoxedParser<'a, I, Expression<'a>>,
+    ) -> impl Parser<'a, I, Self, extra::Err<Rich<'a, Token<'a>>>> + Clone
+    where
+        I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
+    {
+        let default = keyword(Token::DefaultKeyword)
+            .then(just(Token::Comma).or_not())
+            .map(|_| Self::Default);
+
+        let expressions = expression_parser
+            .separated_by(just(Token::Comma))
+            .allow_trailing()
+            .at_least(1)
+            .collect()
+            .map(Self::Expressions);
+
+        choice((default, expressions))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MatchArm<'a> {
+    pub conditions: MatchConditions<'a>,
+    pub expression: Expression<'a>,
+}
+
+impl<'a> MatchArm<'a> {
+    pub fn parser<I>(
+        expression_parser: BoxedParser<'a, I, Expression<'a>>,
+    ) -> impl Parser<'a, I, Self, extra::Err<Rich<'a, Token<'a>>>> + Clone
+    where
+        I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
+    {
+        MatchConditions::parser(expression_parser.clone())
+            .then_ignore(just(Token::DoubleArrow))
+            .then(expression_parser)
+            .map(|(conditions, expression)| Self {
+                conditions,
+                expression,
+            })
+            .labelled("MatchArm")
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MatchExpression<'a> {
+    pub subject: Expression<'a>,
+    pub arms: Vec<MatchArm<'a>>,
+}
+
+impl<'a> MatchExpression<'a> {
+    pub fn parser<I>(
+        expression_parser: BoxedParser<'a, I, Expression<'a>>,
+    ) -> impl Parser<'a, I, Self, extra::Err<Rich<'a, Token<'a>>>> + Clone
+    where
+        I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
+    {
+        let subject = expression_parser
+            .clone()
+            .delimited_by(just(Token::OpenParen), just(Token::CloseParen));
+
+        let arms = MatchArm::parser(expression_parser)
+            .separated_by(just(Token::Comma))
+            .allow_trailing()
+            .collect()
+            .delimited_by(just(Token::OpenBrace), just(Token::CloseBrace));
+
+        keyword(Token::MatchKeyword)
+            .ignore_then(subject)
+            .then(arms)
+            .map(|(subject, arms)| Self { subject, arms })
+            .labelled("MatchExpression")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::{
+        atoms::literal::{integer_literal::IntegerLiteral, Literal},
+        expressions::{
+            literal_expression::LiteralExpression, primary_expression::PrimaryExpression,
+            reserved_word_expression::ReservedWordExpression,
+        },
+        statements::Statement,
+        tokenize,
+    };
+
+    use super::*;
+
+    fn parse(src: &str) -> Result<MatchExpression<'_>, ()> {
+        let tokens = tokenize(src);
+
+        MatchExpression::parser(Expression::parser(Statement::parser().boxed()))
+            .parse(tokens)
+            .into_result()
+            .map_err(|_| ())
+    }
+
+    fn integer(value: &str) -> Expression<'_> {
+        Expression::Primary(PrimaryExpression::Literal(LiteralExpression(
+            Literal::Integer(IntegerLiteral(value)),
+        )))
+    }
+
+    #[test]
+    fn empty() {
+        let res = parse(r#"match (true) {}"#);
+        assert_eq!(
+            res,
+            Ok(MatchExpression {
+                subject: Expression::Primary(PrimaryExpression::ReservedWord(
+                    ReservedWordExpression::True
+                )),
+                arms: vec![]
+            })
+        );
+    }
+
+    #[test]
+    fn simple() {
+        let res = parse(r#"match ($test) { 1, 2 => 3, default => 4 }"#);
+        assert!(matches!(res, Ok(MatchExpression { .. })));
+
+        let arms = res.unwrap().arms;
+        assert_eq!(
+            arms,
+            vec![
+                MatchArm {
+                    conditions: MatchConditions::Expressions(vec![integer("1"), integer("2")]),
+                    expression: integer("3"),
+                },
+                MatchArm {
+                    conditions: MatchConditions::Default,
+                    expression: integer("4"),
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn trailing_commas() {
+        let res = parse(r#"match ($test) { 1, 2, => 3, default, => 4, }"#);
+        assert!(matches!(res.map(|m| m.arms.len()), Ok(2)));
+    }
+
+    #[test]
+    fn missing_arrow_fail() {
+        let res = parse(r#"match ($test) { 1 }"#);
+        assert_eq!(res, Err(()));
+    }
+}
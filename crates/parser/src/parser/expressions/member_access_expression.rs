@@ -6,7 +6,12 @@ use phprs_lexer::Token;
 use super::{dereferencable_expression::DereferencableExpression, Expression};
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
-pub struct MemberAccessExpression<'a>(pub DereferencableExpression<'a>, pub MemberName<'a>);
+pub struct MemberAccessExpression<'a> {
+    pub expression: DereferencableExpression<'a>,
+    pub member: MemberName<'a>,
+    // `?->`
+    pub nullsafe: bool,
+}
 
 impl<'a> MemberAccessExpression<'a> {
     pub fn parser<I>(
@@ -16,9 +21,16 @@ impl<'a> MemberAccessExpression<'a> {
         I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
     {
         DereferencableExpression::parser(expression_parser.clone())
-            .then_ignore(just(Token::Arrow))
+            .then(choice((
+                just(Token::Arrow).to(false),
+                just(Token::QuestionArrow).to(true),
+            )))
             .then(MemberName::parser(expression_parser))
-            .map(|(expression, name)| Self(expression, name))
+            .map(|((expression, nullsafe), member)| Self {
+                expression,
+                member,
+                nullsafe,
+            })
             .labelled("MemberAccessExpression")
     }
 }
@@ -43,6 +55,21 @@ mod tests {
     #[test]
     fn simple() {
         let res = parse(r#""heeey"->hello"#);
-        assert!(matches!(res, Ok(MemberAccessExpression(_, _))));
+        assert!(matches!(
+            res,
+            Ok(MemberAccessExpression {
+                nullsafe: false,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn nullsafe() {
+        let res = parse(r#""heeey"?->hello"#);
+        assert!(matches!(
+            res,
+            Ok(MemberAccessExpression { nullsafe: true, .. })
+        ));
     }
 }
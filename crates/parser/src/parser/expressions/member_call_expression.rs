@@ -13,6 +13,8 @@ use super::Expression;
 pub struct MemberCallExpression<'a> {
     expression: DereferencableExpression<'a>,
     member: MemberName<'a>,
+    // `?->`
+    nullsafe: bool,
     arguments: Vec<ArgumentExpression<'a>>,
 }
 
@@ -24,15 +26,62 @@ impl<'a> MemberCallExpression<'a> {
         I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
     {
         DereferencableExpression::parser(expression_parser.clone())
-            .then_ignore(just(Token::Arrow))
+            .then(choice((
+                just(Token::Arrow).to(false),
+                just(Token::QuestionArrow).to(true),
+            )))
             .then(MemberName::parser(expression_parser.clone()))
             .then_ignore(just(Token::OpenParen))
             .then(ArgumentExpression::list_parser(expression_parser))
             .then_ignore(just(Token::CloseParen))
-            .map(|((expression, member), arguments)| Self {
+            .map(|(((expression, nullsafe), member), arguments)| Self {
                 expression,
                 member,
+                nullsafe,
                 arguments,
             })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::variables::callable::CallableVariable;
+    use crate::parser::variables::Variable;
+    use crate::parser::{statements::Statement, tokenize};
+
+    use super::*;
+
+    fn parse(src: &str) -> Result<Variable<'_>, ()> {
+        let token_stream = tokenize(src);
+
+        Variable::parser(Expression::parser(Statement::parser()))
+            .parse(token_stream)
+            .into_result()
+            .map_err(|_| ())
+    }
+
+    #[test]
+    fn simple() {
+        let res = parse(r#""heeey"->hello(1)"#);
+        assert!(matches!(
+            res,
+            Ok(Variable::Callable(CallableVariable::MemberCallExpression(
+                MemberCallExpression {
+                    nullsafe: false,
+                    ..
+                }
+            )))
+        ));
+    }
+
+    #[test]
+    fn nullsafe() {
+        let res = parse(r#""heeey"?->hello()"#);
+        assert!(matches!(
+            res,
+            Ok(Variable::Callable(CallableVariable::MemberCallExpression(
+                MemberCallExpression { nullsafe: true, .. }
+            )))
+        ));
+    }
+}
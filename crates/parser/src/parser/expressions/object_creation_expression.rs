@@ -1,9 +1,12 @@
-// TODO
-
 use chumsky::prelude::*;
 use chumsky::{error::Rich, extra, input::ValueInput, Parser};
 
+use crate::parser::atoms::attribute::AttributeGroup;
 use crate::parser::atoms::class_type_designator::ClassTypeDesignator;
+use crate::parser::atoms::name::qualified_name::QualifiedName;
+use crate::parser::class::class_member_declaration::ClassMemberDeclaration;
+use crate::parser::keyword;
+use crate::parser::statements::Statement;
 use crate::parser::BoxedParser;
 use phprs_lexer::Token;
 
@@ -13,11 +16,19 @@ use super::Expression;
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub enum ObjectCreationExpression<'a> {
     Simple(ClassTypeDesignator<'a>, Vec<ArgumentExpression<'a>>),
-    Class, // TODO
+    // `new class($argument) extends Base implements Contract { ... }`
+    Class {
+        attributes: Vec<AttributeGroup<'a>>,
+        arguments: Vec<ArgumentExpression<'a>>,
+        extends: Option<QualifiedName<'a>>,
+        implements: Vec<QualifiedName<'a>>,
+        body: Vec<ClassMemberDeclaration<'a>>,
+    },
 }
 
 impl<'a> ObjectCreationExpression<'a> {
     pub fn parser<I>(
+        statement_parser: BoxedParser<'a, I, Statement<'a>>,
         expression_parser: BoxedParser<'a, I, Expression<'a>>,
     ) -> impl Parser<'a, I, Self, extra::Err<Rich<'a, Token<'a>>>> + Clone
     where
@@ -28,9 +39,128 @@ impl<'a> ObjectCreationExpression<'a> {
             .ignore_then(ArgumentExpression::list_parser(expression_parser.clone()))
             .then_ignore(just(Token::CloseParen));
 
-        just(Token::NewKeyword)
-            .ignore_then(ClassTypeDesignator::parser(expression_parser))
-            .then(arguments)
-            .map(|(designator, arguments)| ObjectCreationExpression::Simple(designator, arguments))
+        let simple = ClassTypeDesignator::parser(expression_parser.clone())
+            .then(arguments.clone())
+            .map(|(designator, arguments)| ObjectCreationExpression::Simple(designator, arguments));
+
+        let extends = keyword(Token::ExtendsKeyword)
+            .ignore_then(QualifiedName::parser())
+            .or_not();
+
+        let implements = keyword(Token::ImplementsKeyword)
+            .ignore_then(
+                QualifiedName::parser()
+                    .separated_by(just(Token::Comma))
+                    .at_least(1)
+                    .collect(),
+            )
+            .or_not()
+            .map(|names| names.unwrap_or_default());
+
+        let members = ClassMemberDeclaration::parser(statement_parser, expression_parser.clone())
+            .repeated()
+            .collect();
+
+        let body = just(Token::OpenBrace)
+            .ignore_then(members)
+            .then_ignore(just(Token::CloseBrace));
+
+        let class = AttributeGroup::list_parser(expression_parser)
+            .then_ignore(keyword(Token::ClassKeyword))
+            .then(
+                arguments
+                    .or_not()
+                    .map(|arguments| arguments.unwrap_or_default()),
+            )
+            .then(extends)
+            .then(implements)
+            .then(body)
+            .map(
+                |((((attributes, arguments), extends), implements), body)| Self::Class {
+                    attributes,
+                    arguments,
+                    extends,
+                    implements,
+                    body,
+                },
+            );
+
+        keyword(Token::NewKeyword)
+            .ignore_then(choice((class, simple)))
+            .labelled("ObjectCreationExpression")
+            .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::tokenize;
+
+    use super::*;
+
+    fn parse(src: &str) -> Result<ObjectCreationExpression<'_>, ()> {
+        let tokens = tokenize(src);
+
+        let statement_parser = Statement::parser().boxed();
+        ObjectCreationExpression::parser(
+            statement_parser.clone(),
+            Expression::parser(statement_parser),
+        )
+        .parse(tokens)
+        .into_result()
+        .map_err(|_| ())
+    }
+
+    #[test]
+    fn simple() {
+        let res = parse(r#"new Foo($a, 5)"#);
+        assert!(matches!(
+            res,
+            Ok(ObjectCreationExpression::Simple(_, arguments)) if arguments.len() == 2
+        ));
+    }
+
+    #[test]
+    fn anonymous_class() {
+        let res = parse(r#"new class {}"#);
+        assert!(matches!(
+            res,
+            Ok(ObjectCreationExpression::Class { arguments, body, .. })
+                if arguments.is_empty() && body.is_empty()
+        ));
+    }
+
+    #[test]
+    fn anonymous_class_arguments() {
+        let res = parse(
+            r#"new class($logger, level: 3) extends Handler implements Countable, Stringable {
+                use Loggable;
+
+                public function __construct(private $logger, private int $level) {}
+
+                public function count(): int {
+                    return 0;
+                }
+            }"#,
+        );
+        assert!(matches!(
+            res,
+            Ok(ObjectCreationExpression::Class {
+                arguments,
+                extends: Some(_),
+                implements,
+                body,
+                ..
+            }) if arguments.len() == 2 && implements.len() == 2 && body.len() == 3
+        ));
+    }
+
+    #[test]
+    fn anonymous_class_attributes() {
+        let res = parse(r#"new #[Stub] class {}"#);
+        assert!(matches!(
+            res,
+            Ok(ObjectCreationExpression::Class { attributes, .. }) if attributes.len() == 1
+        ));
     }
 }
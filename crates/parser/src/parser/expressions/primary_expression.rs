@@ -1,3 +1,4 @@
+use crate::parser::atoms::intrinsic::list_intrinsic::ListIntrinsic;
 use crate::parser::atoms::intrinsic::Intrinsic;
 use crate::parser::statements::Statement;
 use crate::parser::variables::Variable;
@@ -5,18 +6,24 @@ use crate::parser::BoxedParser;
 
 use super::anonymous_function_expression::AnonymousFunctionCreationExpression;
 use super::array_creation_expression::ArrayCreationExpression;
+use super::arrow_function_expression::ArrowFunctionCreationExpression;
 use super::byref_assignment_expression::ByrefAssigmentExpression;
 use super::class_constant_access_expression::ClassConstantAccessExpression;
 use super::constant_access_expression::ConstantAccessExpression;
 use super::decrement_expression::DecrementExpression;
+use super::dereferencable_expression::DereferencableExpression;
 use super::include_expression::IncludeExpression;
 use super::include_once_expression::IncludeOnceExpression;
 use super::increment_expression::InscrementExpression;
+use super::interpolated_string_expression::InterpolatedStringExpression;
 use super::literal_expression::LiteralExpression;
+use super::magic_constant_expression::MagicConstantExpression;
+use super::match_expression::MatchExpression;
 use super::object_creation_expression::ObjectCreationExpression;
 use super::require_expression::RequireExpression;
 use super::require_once_expression::RequireOnceExpression;
 use super::reserved_word_expression::ReservedWordExpression;
+use super::shell_command_expression::ShellCommandExpression;
 use super::Expression;
 use chumsky::prelude::*;
 use chumsky::{input::ValueInput, Parser};
@@ -29,17 +36,21 @@ pub enum PrimaryExpression<'a> {
     ClassConstantAccess(ClassConstantAccessExpression<'a>),
     ConstantAccess(ConstantAccessExpression<'a>),
     Literal(LiteralExpression<'a>),
+    InterpolatedString(Box<InterpolatedStringExpression<'a>>),
     ArrayCreation(ArrayCreationExpression<'a>),
     Intrinsic(Box<Intrinsic<'a>>),
-    AnonymousFunctionCreation(AnonymousFunctionCreationExpression<'a>),
-    ObjectCreation(Box<ObjectCreationExpression<'a>>), // TODO
+    AnonymousFunctionCreation(Box<AnonymousFunctionCreationExpression<'a>>),
+    ArrowFunctionCreation(Box<ArrowFunctionCreationExpression<'a>>),
+    Match(Box<MatchExpression<'a>>),
+    ObjectCreation(Box<ObjectCreationExpression<'a>>),
     Increment(Box<InscrementExpression<'a>>),
     Decrement(Box<DecrementExpression<'a>>),
     ByrefAssignment(Box<ByrefAssigmentExpression<'a>>),
-    ShellCommand, // TODO
+    ShellCommand(ShellCommandExpression<'a>),
 
     // TODO: Move to Expression
     ReservedWord(ReservedWordExpression),
+    MagicConstant(MagicConstantExpression),
     Require(Box<RequireExpression<'a>>),
     RequireOnce(Box<RequireOnceExpression<'a>>),
     Include(Box<IncludeExpression<'a>>),
@@ -65,20 +76,59 @@ impl<'a> PrimaryExpression<'a> {
 
         let literal = LiteralExpression::parser().map(Self::Literal);
 
-        let array_creation =
-            ArrayCreationExpression::parser(expression_parser.clone()).map(Self::ArrayCreation);
+        let interpolated_string = InterpolatedStringExpression::parser(expression_parser.clone())
+            .map(|v| Self::InterpolatedString(Box::new(v)));
+
+        let shell_command =
+            ShellCommandExpression::parser(expression_parser.clone()).map(Self::ShellCommand);
+
+        // NOTE: An array followed by `=` is a list assignment target, checked here where its
+        // span is known
+        let array_creation = ArrayCreationExpression::parser(expression_parser.clone())
+            .then(just(Token::Equals).rewind().or_not())
+            .validate(|(array, assigned), e, emitter| {
+                if assigned.is_some() {
+                    if let Err(message) = ListIntrinsic::from_array(&array) {
+                        emitter.emit(Rich::custom(e.span(), message));
+                    }
+                }
+
+                Self::ArrayCreation(array)
+            });
 
         let intrinsic =
             Intrinsic::parser(expression_parser.clone()).map(|v| Self::Intrinsic(Box::new(v)));
 
         let anonymous_function_creation = AnonymousFunctionCreationExpression::parser(
-            statement_parser,
+            statement_parser.clone(),
             expression_parser.clone(),
         )
-        .map(Self::AnonymousFunctionCreation);
-
-        let object_creation = ObjectCreationExpression::parser(expression_parser.clone())
-            .map(|v| Self::ObjectCreation(Box::new(v)));
+        .map(|v| Self::AnonymousFunctionCreation(Box::new(v)));
+
+        let arrow_function_creation =
+            ArrowFunctionCreationExpression::parser(expression_parser.clone())
+                .map(|v| Self::ArrowFunctionCreation(Box::new(v)));
+
+        let match_ =
+            MatchExpression::parser(expression_parser.clone()).map(|v| Self::Match(Box::new(v)));
+
+        // NOTE: PHP 8.4 allows member access on `new Foo()` without wrapping parentheses
+        let postfix = Variable::postfix_parser(expression_parser.clone());
+        let object_creation =
+            ObjectCreationExpression::parser(statement_parser, expression_parser.clone())
+                .then(postfix.repeated().collect::<Vec<_>>())
+                .map(|(object, postfixes)| {
+                    let mut postfixes = postfixes.into_iter();
+                    let Some(first) = postfixes.next() else {
+                        return Self::ObjectCreation(Box::new(object));
+                    };
+
+                    let variable = postfixes.fold(
+                        first(DereferencableExpression::ObjectCreation(Box::new(object))),
+                        |a, b| b(DereferencableExpression::Variable(Box::new(a))),
+                    );
+                    Self::Variable(Box::new(variable))
+                });
 
         let increment = InscrementExpression::parser(expression_parser.clone())
             .map(|v| Self::Increment(Box::new(v)));
@@ -91,6 +141,8 @@ impl<'a> PrimaryExpression<'a> {
 
         let reserved_word = ReservedWordExpression::parser().map(Self::ReservedWord);
 
+        let magic_constant = MagicConstantExpression::parser().map(Self::MagicConstant);
+
         let require_once = RequireOnceExpression::parser(expression_parser.clone())
             .map(|e| Self::RequireOnce(Box::new(e)));
 
@@ -110,21 +162,27 @@ impl<'a> PrimaryExpression<'a> {
 
         choice((
             anonymous_function_creation,
-            class_constant_access,
-            constant_access,
+            arrow_function_creation,
+            match_,
             literal,
+            interpolated_string,
+            shell_command,
             array_creation,
             object_creation,
             intrinsic,
             increment,
             decrement,
             byref_assignment,
+            variable,
+            // NOTE: After `variable` so `Foo::bar()` is not parsed as a constant access
+            class_constant_access,
+            constant_access,
             reserved_word,
+            magic_constant,
             require_once,
             require,
             include,
             include_once,
-            variable,
             expression,
         ))
         .labelled("Expression")
@@ -134,7 +192,9 @@ impl<'a> PrimaryExpression<'a> {
 
 #[cfg(test)]
 mod tests {
+    use crate::parser::expressions::member_access_expression::MemberAccessExpression;
     use crate::parser::tokenize;
+    use crate::parser::variables::callable::CallableVariable;
 
     use super::*;
 
@@ -167,6 +227,41 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn magic_constant() {
+        let res = parse(r#"__METHOD__"#);
+        assert_eq!(
+            res,
+            Ok(PrimaryExpression::MagicConstant(
+                MagicConstantExpression::Method
+            ))
+        );
+    }
+
+    #[test]
+    fn function_call() {
+        assert!(matches!(
+            parse(r#"array_map(fn($x) => $x * 2, $xs)"#),
+            Ok(PrimaryExpression::Variable(_))
+        ));
+    }
+
+    #[test]
+    fn static_call() {
+        assert!(matches!(
+            parse(r#"Foo::bar()"#),
+            Ok(PrimaryExpression::Variable(_))
+        ));
+    }
+
+    #[test]
+    fn shell_command() {
+        assert!(matches!(
+            parse(r#"`whoami`"#),
+            Ok(PrimaryExpression::ShellCommand(_))
+        ));
+    }
+
     #[test]
     fn literal() {
         assert!(matches!(
@@ -191,6 +286,61 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn object_creation_dereference() {
+        let Ok(PrimaryExpression::Variable(variable)) = parse(r#"new Test()->name"#) else {
+            panic!("expected a variable");
+        };
+        assert!(matches!(
+            *variable,
+            Variable::MemberAccessExpression(MemberAccessExpression {
+                expression: DereferencableExpression::ObjectCreation(_),
+                ..
+            })
+        ));
+
+        assert!(matches!(
+            parse(r#"new Test()->run()->result"#),
+            Ok(PrimaryExpression::Variable(v)) if matches!(*v, Variable::MemberAccessExpression(..))
+        ));
+
+        assert!(matches!(
+            parse(r#"new Foo()['x']"#),
+            Ok(PrimaryExpression::Variable(v)) if matches!(
+                *v,
+                Variable::SubscriptExpression(DereferencableExpression::ObjectCreation(_), Some(_))
+            )
+        ));
+
+        assert!(matches!(
+            parse(r#"new Foo()::X"#),
+            Ok(PrimaryExpression::Variable(v)) if matches!(*v, Variable::ClassConstantAccessExpression(_))
+        ));
+
+        assert!(matches!(
+            parse(r#"new Foo()::bar()"#),
+            Ok(PrimaryExpression::Variable(v)) if matches!(
+                *v,
+                Variable::Callable(CallableVariable::ScopedCallExpression(_))
+            )
+        ));
+    }
+
+    #[test]
+    fn object_creation_nested() {
+        let src = format!("{}{}", "new A(".repeat(32), ")".repeat(32));
+        assert!(matches!(
+            parse(&src),
+            Ok(PrimaryExpression::ObjectCreation(_))
+        ));
+
+        let src = format!("{}{}", "new class(".repeat(32), ") {}".repeat(32));
+        assert!(matches!(
+            parse(&src),
+            Ok(PrimaryExpression::ObjectCreation(_))
+        ));
+    }
+
     #[test]
     fn intrinsic() {
         assert!(matches!(
@@ -207,6 +357,27 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn arrow_function_creation() {
+        assert!(matches!(
+            parse(r#"fn($x) => $x * 2"#),
+            Ok(PrimaryExpression::ArrowFunctionCreation(_))
+        ));
+
+        assert!(matches!(
+            parse(r#"static fn() => 1"#),
+            Ok(PrimaryExpression::ArrowFunctionCreation(_))
+        ));
+    }
+
+    #[test]
+    fn match_() {
+        assert!(matches!(
+            parse(r#"match ($test) { 1 => true, default => false }"#),
+            Ok(PrimaryExpression::Match(_))
+        ));
+    }
+
     #[test]
     fn increment() {
         assert!(matches!(
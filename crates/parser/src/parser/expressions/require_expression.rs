@@ -1,3 +1,4 @@
+use crate::parser::keyword;
 use crate::parser::BoxedParser;
 use chumsky::{input::ValueInput, prelude::*};
 use phprs_lexer::Token;
@@ -16,7 +17,7 @@ impl<'a> RequireExpression<'a> {
     where
         I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
     {
-        just(Token::RequireKeyword)
+        keyword(Token::RequireKeyword)
             .ignore_then(expression_parser)
             .map(|expression| Self { expression })
             .labelled("Require expression")
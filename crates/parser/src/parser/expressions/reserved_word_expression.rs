@@ -16,8 +16,8 @@ impl<'a> ReservedWordExpression {
         I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
     {
         select! {
-            Token::TrueReservedWord => Self::True,
-            Token::FalseReservedWord => Self::False,
+            Token::TrueReservedWord(_) => Self::True,
+            Token::FalseReservedWord(_) => Self::False,
             Token::NullReservedWord => Self::Null,
         }
         .labelled("ReservedWord expression")
@@ -0,0 +1,92 @@
+use chumsky::prelude::*;
+use chumsky::{error::Rich, extra, input::ValueInput, Parser};
+
+use crate::parser::atoms::member_name::MemberName;
+use crate::parser::atoms::scope_resolution_qualifier::ScopeResolutionQualifier;
+use crate::parser::BoxedParser;
+use phprs_lexer::Token;
+
+use super::argument_expression::ArgumentExpression;
+use super::Expression;
+
+// `Foo::bar()`
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ScopedCallExpression<'a> {
+    pub scope: ScopeResolutionQualifier<'a>,
+    pub member: MemberName<'a>,
+    pub arguments: Vec<ArgumentExpression<'a>>,
+}
+
+impl<'a> ScopedCallExpression<'a> {
+    pub fn parser<I>(
+        expression_parser: BoxedParser<'a, I, Expression<'a>>,
+    ) -> impl Parser<'a, I, Self, extra::Err<Rich<'a, Token<'a>>>> + Clone
+    where
+        I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
+    {
+        ScopeResolutionQualifier::parser()
+            .then_ignore(just(Token::ColonColon))
+            .then(MemberName::parser(expression_parser.clone()))
+            .then_ignore(just(Token::OpenParen))
+            .then(ArgumentExpression::list_parser(expression_parser))
+            .then_ignore(just(Token::CloseParen))
+            .map(|((scope, member), arguments)| Self {
+                scope,
+                member,
+                arguments,
+            })
+            .labelled("ScopedCallExpression")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::atoms::{name::Name, relative_scope::RelativeScope};
+    use crate::parser::{statements::Statement, tokenize};
+
+    use super::*;
+
+    fn parse(src: &str) -> Result<ScopedCallExpression<'_>, ()> {
+        let tokens = tokenize(src);
+
+        ScopedCallExpression::parser(Expression::parser(Statement::parser().boxed()))
+            .parse(tokens)
+            .into_result()
+            .map_err(|_| ())
+    }
+
+    #[test]
+    fn static_call() {
+        let res = parse(r#"Foo::create($a, 5)"#).unwrap();
+        assert!(matches!(
+            res.scope,
+            ScopeResolutionQualifier::QualifiedName(_)
+        ));
+        assert_eq!(res.member, MemberName::Name(Name("create")));
+        assert_eq!(res.arguments.len(), 2);
+    }
+
+    #[test]
+    fn relative_scope() {
+        let res = parse(r#"parent::boot()"#).unwrap();
+        assert_eq!(
+            res.scope,
+            ScopeResolutionQualifier::RelativeScope(RelativeScope::Parent)
+        );
+    }
+
+    #[test]
+    fn constant_fail() {
+        let res = parse(r#"Foo::BAR"#);
+        assert_eq!(res, Err(()));
+    }
+
+    #[test]
+    fn keyword_member() {
+        let res = parse(r#"parent::__construct($a)"#).unwrap();
+        assert_eq!(res.member, MemberName::Name(Name("__construct")));
+
+        let res = parse(r#"Query::list()"#).unwrap();
+        assert_eq!(res.member, MemberName::Name(Name("list")));
+    }
+}
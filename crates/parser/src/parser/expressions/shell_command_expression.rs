@@ -0,0 +1,87 @@
+use chumsky::prelude::*;
+use chumsky::{error::Rich, extra, input::ValueInput, Parser};
+
+use crate::parser::BoxedParser;
+use phprs_lexer::Token;
+
+use super::interpolated_string_expression::InterpolatedStringPart;
+use super::Expression;
+
+// `ls -la $directory`
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ShellCommandExpression<'a> {
+    pub parts: Vec<InterpolatedStringPart<'a>>,
+}
+
+impl<'a> ShellCommandExpression<'a> {
+    pub fn parser<I>(
+        expression_parser: BoxedParser<'a, I, Expression<'a>>,
+    ) -> impl Parser<'a, I, Self, extra::Err<Rich<'a, Token<'a>>>> + Clone
+    where
+        I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
+    {
+        InterpolatedStringPart::list_parser(expression_parser)
+            .delimited_by(just(Token::Backtick), just(Token::Backtick))
+            .map(|parts| Self { parts })
+            .labelled("ShellCommandExpression")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::atoms::name::variable_name::VariableName;
+    use crate::parser::{statements::Statement, tokenize};
+
+    use super::*;
+
+    fn parse(src: &str) -> Result<ShellCommandExpression<'_>, ()> {
+        let tokens = tokenize(src);
+
+        ShellCommandExpression::parser(Expression::parser(Statement::parser().boxed()))
+            .parse(tokens)
+            .into_result()
+            .map_err(|_| ())
+    }
+
+    #[test]
+    fn simple() {
+        let res = parse(r#"`ls -la`"#);
+        assert_eq!(
+            res,
+            Ok(ShellCommandExpression {
+                parts: vec![InterpolatedStringPart::Literal("ls -la")]
+            })
+        );
+    }
+
+    #[test]
+    fn empty() {
+        let res = parse(r#"``"#);
+        assert_eq!(res, Ok(ShellCommandExpression { parts: vec![] }));
+    }
+
+    #[test]
+    fn interpolation() {
+        let res = parse(r#"`git log {$options->format} -- $path`"#).unwrap();
+        assert!(matches!(
+            &res.parts[..],
+            [
+                InterpolatedStringPart::Literal("git log "),
+                InterpolatedStringPart::Expression(_),
+                InterpolatedStringPart::Literal(" -- "),
+                InterpolatedStringPart::Variable(VariableName("$path")),
+            ]
+        ));
+    }
+
+    #[test]
+    fn escaped_backtick() {
+        let res = parse(r#"`echo \`date\``"#);
+        assert_eq!(
+            res,
+            Ok(ShellCommandExpression {
+                parts: vec![InterpolatedStringPart::Literal(r#"echo \`date\`"#)]
+            })
+        );
+    }
+}
@@ -5,27 +5,49 @@ use phprs_lexer::Token;
 
 use crate::parser::class::class_const_declaration::ClassConstDeclaration;
 use crate::parser::class::method_declaration::MethodDeclaration;
+use crate::parser::class::property_declaration::PropertyDeclaration;
+use crate::parser::expressions::Expression;
 use crate::parser::statements::Statement;
 use crate::parser::BoxedParser;
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub enum InterfaceMemberDeclaration<'a> {
     ClassConstDeclaration(ClassConstDeclaration<'a>),
-    MethodDeclaration(MethodDeclaration<'a>),
+    MethodDeclaration(Box<MethodDeclaration<'a>>),
+    // PHP 8.4 `public string $name { get; }`
+    PropertyDeclaration(PropertyDeclaration<'a>),
 }
 
 impl<'a> InterfaceMemberDeclaration<'a> {
     pub fn parser<I>(
         statement_parser: BoxedParser<'a, I, Statement<'a>>,
+        expression_parser: BoxedParser<'a, I, Expression<'a>>,
     ) -> impl Parser<'a, I, Self, extra::Err<Rich<'a, Token<'a>>>> + Clone
     where
         I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
     {
-        let class_const_declaration = ClassConstDeclaration::parser(statement_parser.clone())
+        let class_const_declaration = ClassConstDeclaration::parser(expression_parser.clone())
             .map(Self::ClassConstDeclaration);
-        let method_declaration =
-            MethodDeclaration::parser(statement_parser).map(Self::MethodDeclaration);
+        let property_declaration =
+            PropertyDeclaration::parser(statement_parser.clone(), expression_parser.clone())
+                .validate(|property, e, emitter| {
+                    if property.hooks().is_empty() {
+                        emitter.emit(Rich::custom(
+                            e.span(),
+                            "Interfaces may only include hooked properties",
+                        ));
+                    }
 
-        choice((class_const_declaration, method_declaration)).labelled("InterfaceMemberDeclaration")
+                    Self::PropertyDeclaration(property)
+                });
+        let method_declaration = MethodDeclaration::parser(statement_parser, expression_parser)
+            .map(|method| Self::MethodDeclaration(Box::new(method)));
+
+        choice((
+            class_const_declaration,
+            property_declaration,
+            method_declaration,
+        ))
+        .labelled("InterfaceMemberDeclaration")
     }
 }
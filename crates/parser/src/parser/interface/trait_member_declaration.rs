@@ -8,6 +8,7 @@ use crate::parser::class::destructor_declaration::DestructorDeclaration;
 use crate::parser::class::method_declaration::MethodDeclaration;
 use crate::parser::class::property_declaration::PropertyDeclaration;
 use crate::parser::class::trait_use_clause::TraitUseClause;
+use crate::parser::expressions::Expression;
 use crate::parser::statements::Statement;
 use crate::parser::BoxedParser;
 
@@ -23,18 +24,23 @@ pub enum TraitMemberDeclaration<'a> {
 impl<'a> TraitMemberDeclaration<'a> {
     pub fn parser<I>(
         statement_parser: BoxedParser<'a, I, Statement<'a>>,
+        expression_parser: BoxedParser<'a, I, Expression<'a>>,
     ) -> impl Parser<'a, I, Self, extra::Err<Rich<'a, Token<'a>>>> + Clone
     where
         I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
     {
         let property_declaration =
-            PropertyDeclaration::parser(statement_parser.clone()).map(Self::PropertyDeclarationd);
+            PropertyDeclaration::parser(statement_parser.clone(), expression_parser.clone())
+                .map(Self::PropertyDeclarationd);
         let method_declaration =
-            MethodDeclaration::parser(statement_parser.clone()).map(Self::MethodDeclaration);
-        let constructor_declaration = ConstructorDeclaration::parser(statement_parser.clone())
-            .map(Self::ConstructorDeclaration);
+            MethodDeclaration::parser(statement_parser.clone(), expression_parser.clone())
+                .map(Self::MethodDeclaration);
+        let constructor_declaration =
+            ConstructorDeclaration::parser(statement_parser.clone(), expression_parser.clone())
+                .map(Self::ConstructorDeclaration);
         let destructor_declaration =
-            DestructorDeclaration::parser(statement_parser).map(Self::DestructorDeclaration);
+            DestructorDeclaration::parser(statement_parser, expression_parser)
+                .map(Self::DestructorDeclaration);
         let trait_use_clause = TraitUseClause::parser().map(Self::TraitUseClause);
 
         choice((
@@ -17,6 +17,21 @@ use script::Script;
 
 type BoxedParser<'a, I, O> = Boxed<'a, 'a, I, O, extra::Err<Rich<'a, Token<'a>>>>;
 
+// NOTE: Keyword tokens carry their source spelling, so they are matched on their kind only
+pub fn keyword<'a, I>(
+    keyword: fn(&'a str) -> Token<'a>,
+) -> impl Parser<'a, I, Token<'a>, extra::Err<Rich<'a, Token<'a>>>> + Clone
+where
+    I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
+{
+    let expected = keyword("");
+    let label = format!("{expected:?}").replace("(\"\")", "");
+
+    any()
+        .filter(move |token| std::mem::discriminant(token) == std::mem::discriminant(&expected))
+        .labelled(label)
+}
+
 pub fn parse(content: &str) {
     let token_iter = phprs_lexer::lexer(content).map(|(tok, span)| match tok {
         Ok(tok) => (tok, span.into()),
@@ -48,7 +63,7 @@ pub fn parse(content: &str) {
 
 pub fn tokenize(src: &str) -> impl chumsky::input::ValueInput<Token = Token, Span = SimpleSpan> {
     // ) -> chumsky::input::SpannedInput<Token, SimpleSpan, BoxedStream<(Token, SimpleSpan)>> {
-    let token_iter = phprs_lexer::lexer(src)
+    let token_iter = phprs_lexer::script_lexer(src)
         .filter(|(tok, _)| !matches!(tok, Ok(Token::EndOfFile)))
         .map(|(tok, span)| match tok {
             Ok(tok) => (tok, span.into()),
@@ -12,9 +12,49 @@ impl<'a> Script<'a> {
     where
         I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
     {
+        // NOTE: Text before the first `<?php` is parsed as an `InlineHtml` statement
         just(Token::ScriptSectionStartTag)
+            .or_not()
             .ignore_then(Statement::list_parser(Statement::parser().boxed()))
             .then_ignore(just(Token::EndOfFile))
             .map(|statements| Script { statements })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::statements::inline_html_statement::InlineHtmlStatement;
+
+    use super::*;
+
+    #[test]
+    fn inline_html() {
+        let res = crate::parse("<ul>\n<?php foreach ($items as $item): ?>\n<li><?= $item ?></li>\n<?php endforeach; ?>\n</ul>\n").unwrap();
+        assert!(matches!(
+            &res.statements[..],
+            [
+                Statement::InlineHtml(InlineHtmlStatement { text: "<ul>\n" }),
+                Statement::Foreach(_),
+                Statement::InlineHtml(InlineHtmlStatement { text: "</ul>\n" }),
+            ]
+        ));
+    }
+
+    #[test]
+    fn closing_tag_terminates_statement() {
+        let res = crate::parse("<?php echo 'hello' ?>").unwrap();
+        assert!(matches!(&res.statements[..], [Statement::Echo(_)]));
+
+        let res = crate::parse("<?php echo 'a' ?><?php echo 'b'; ?>\n").unwrap();
+        assert!(matches!(
+            &res.statements[..],
+            [Statement::Echo(_), Statement::Echo(_)]
+        ));
+    }
+
+    #[test]
+    fn shebang_and_bom() {
+        let res = crate::parse("\u{feff}#!/usr/bin/env php\n<?php\necho 1;\n").unwrap();
+        assert!(matches!(&res.statements[..], [Statement::Echo(_)]));
+    }
+}
@@ -5,6 +5,7 @@ pub mod continue_statement;
 pub mod declare_statement;
 pub mod do_statement;
 pub mod echo_statement;
+pub mod echo_tag_statement;
 pub mod enum_declaration;
 pub mod expression_statement;
 pub mod for_statement;
@@ -14,6 +15,7 @@ pub mod function_static_declaration;
 pub mod global_declaration;
 pub mod goto_statement;
 pub mod if_statement;
+pub mod inline_html_statement;
 pub mod interface_declaration;
 pub mod named_label_statement;
 pub mod namespace_definition;
@@ -36,6 +38,7 @@ use continue_statement::ContinueStatement;
 use declare_statement::DeclareStatement;
 use do_statement::DoStatement;
 use echo_statement::EchoStatement;
+use echo_tag_statement::EchoTagStatement;
 use enum_declaration::EnumDeclaration;
 use expression_statement::ExpressionStatement;
 use for_statement::ForStatement;
@@ -45,6 +48,7 @@ use function_static_declaration::FunctionStaticDeclaration;
 use global_declaration::GlobalDeclaration;
 use goto_statement::GotoStatement;
 use if_statement::IfStatement;
+use inline_html_statement::InlineHtmlStatement;
 use interface_declaration::InterfaceDeclaration;
 use named_label_statement::NamedLabelStatement;
 use namespace_definition::NamespaceDefinition;
@@ -60,6 +64,13 @@ use while_statement::WhileStatement;
 
 use super::BoxedParser;
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum StatementSyntax {
+    Standard,
+    // if (...): ... endif;
+    Alternative,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub enum Statement<'a> {
     Compound(CompoundStatement<'a>),
@@ -81,6 +92,7 @@ pub enum Statement<'a> {
     Try(TryStatement<'a>),
     Declare(DeclareStatement<'a>),
     Echo(EchoStatement<'a>),
+    EchoTag(EchoTagStatement<'a>),
     Unset(UnsetStatement<'a>),
     ConstDeclaration(ConstDeclaration<'a>),
     FunctionDefinition(FunctionDefinition<'a>),
@@ -92,6 +104,7 @@ pub enum Statement<'a> {
     NamespaceUseDeclaration(NamespaceUseDeclaration<'a>),
     GlobalDeclaration(GlobalDeclaration<'a>),
     FunctionStaticDeclaration(FunctionStaticDeclaration<'a>),
+    InlineHtml(InlineHtmlStatement<'a>),
 }
 
 impl<'a> Statement<'a> {
@@ -106,11 +119,13 @@ impl<'a> Statement<'a> {
             let expression_statement =
                 ExpressionStatement::parser(parser.clone().boxed()).map(Self::Expression);
             let echo_statement = EchoStatement::parser(parser.clone().boxed()).map(Self::Echo);
+            let echo_tag_statement =
+                EchoTagStatement::parser(parser.clone().boxed()).map(Self::EchoTag);
+            let inline_html_statement = InlineHtmlStatement::parser().map(Self::InlineHtml);
 
             let namespace_use_declaration =
                 NamespaceUseDeclaration::parser().map(Self::NamespaceUseDeclaration);
 
-            // TODO: Handle if(): endif;
             let if_statement = IfStatement::parser(parser.clone().boxed())
                 .map(|statement| Self::If(Box::new(statement)));
             // Ig parser chaining could be better here
@@ -180,7 +195,7 @@ impl<'a> Statement<'a> {
                 interface_declaration,
             ));
 
-            let c2 = choice((trait_declaration,));
+            let c2 = choice((trait_declaration, inline_html_statement, echo_tag_statement));
 
             choice((c1, c2)).boxed()
         })
@@ -188,12 +203,31 @@ impl<'a> Statement<'a> {
         .boxed()
     }
 
+    // `?>` also ends a statement, it is then consumed by the following `InlineHtml`
+    pub fn terminator_parser<I>() -> impl Parser<'a, I, (), extra::Err<Rich<'a, Token<'a>>>> + Clone
+    where
+        I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
+    {
+        choice((
+            just(Token::Semicolon).ignored(),
+            just(Token::ScriptSectionEndTag).rewind().ignored(),
+        ))
+    }
+
     pub fn list_parser<I>(
         statement_parser: BoxedParser<'a, I, Statement<'a>>,
     ) -> impl Parser<'a, I, Vec<Self>, extra::Err<Rich<'a, Token<'a>>>> + Clone
     where
         I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
     {
-        statement_parser.repeated().collect::<Vec<_>>()
+        // NOTE: `?>` without inline HTML before the next `<?php` only ends the previous statement
+        let empty_tags = just(Token::ScriptSectionEndTag)
+            .then(just(Token::ScriptSectionStartTag).or_not())
+            .to(None);
+
+        choice((statement_parser.map(Some), empty_tags))
+            .repeated()
+            .collect::<Vec<_>>()
+            .map(|statements| statements.into_iter().flatten().collect())
     }
 }
@@ -1,10 +1,13 @@
 use chumsky::{input::ValueInput, span::SimpleSpan};
 use chumsky::{prelude::*, Parser};
 
+use crate::parser::atoms::attribute::AttributeGroup;
 use crate::parser::atoms::class_modifier::ClassModifier;
 use crate::parser::atoms::name::qualified_name::QualifiedName;
 use crate::parser::atoms::name::Name;
 use crate::parser::class::class_member_declaration::ClassMemberDeclaration;
+use crate::parser::expressions::Expression;
+use crate::parser::keyword;
 use crate::parser::BoxedParser;
 use phprs_lexer::Token;
 
@@ -12,8 +15,9 @@ use super::Statement;
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct ClassDeclaration<'a> {
+    attributes: Vec<AttributeGroup<'a>>,
     name: Name<'a>,
-    modifier: Option<ClassModifier>,
+    modifiers: Vec<ClassModifier>,
     extends: Option<QualifiedName<'a>>,
     implements: Vec<QualifiedName<'a>>,
     body: Vec<ClassMemberDeclaration<'a>>,
@@ -26,12 +30,14 @@ impl<'a> ClassDeclaration<'a> {
     where
         I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
     {
-        let modifier = ClassModifier::parser().or_not();
-        let extends = just(Token::ExtendsKeyword)
+        let expression_parser = Expression::parser(statement_parser.clone());
+        let attributes = AttributeGroup::list_parser(expression_parser.clone());
+        let modifiers = ClassModifier::parser().repeated().collect();
+        let extends = keyword(Token::ExtendsKeyword)
             .ignore_then(QualifiedName::parser())
             .or_not();
 
-        let implements = just(Token::ImplementsKeyword)
+        let implements = keyword(Token::ImplementsKeyword)
             .ignore_then(
                 QualifiedName::parser()
                     .separated_by(just(Token::Comma))
@@ -43,25 +49,117 @@ impl<'a> ClassDeclaration<'a> {
 
         let body = just(Token::OpenBrace)
             .ignore_then(
-                ClassMemberDeclaration::parser(statement_parser)
+                ClassMemberDeclaration::parser(statement_parser, expression_parser)
                     .repeated()
                     .collect(),
             )
             .then_ignore(just(Token::CloseBrace));
 
-        modifier
-            .then_ignore(just(Token::ClassKeyword))
+        attributes
+            .then(modifiers)
+            .then_ignore(keyword(Token::ClassKeyword))
             .then(Name::parser())
             .then(extends)
             .then(implements)
             .then(body)
-            .map(|((((modifier, name), extends), implements), body)| Self {
-                name,
-                modifier,
-                extends,
-                implements,
-                body,
-            })
+            .map(
+                |(((((attributes, modifiers), name), extends), implements), body)| Self {
+                    attributes,
+                    name,
+                    modifiers,
+                    extends,
+                    implements,
+                    body,
+                },
+            )
             .labelled("ClassDeclaration")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::tokenize;
+
+    use super::*;
+
+    fn parse(src: &str) -> Result<ClassDeclaration<'_>, ()> {
+        let tokens = tokenize(src);
+
+        ClassDeclaration::parser(Statement::parser())
+            .parse(tokens)
+            .into_result()
+            .map_err(|_| ())
+    }
+
+    #[test]
+    fn readonly_class() {
+        let res = parse(r#"final readonly class Money {}"#).unwrap();
+        assert_eq!(
+            res.modifiers,
+            vec![ClassModifier::Final, ClassModifier::Readonly]
+        );
+    }
+
+    #[test]
+    fn promoted_readonly_parameters() {
+        let res = parse(
+            r#"class Point {
+                public function __construct(public readonly int $x, readonly protected int $y) {}
+            }"#,
+        );
+        assert!(matches!(
+            res.unwrap().body[..],
+            [ClassMemberDeclaration::ConstructorDeclaration(_)]
+        ));
+    }
+
+    #[test]
+    fn abstract_hooked_property() {
+        let res = parse(
+            r#"abstract class Shape {
+                abstract public string $name { get; }
+                final public int $sides = 0 { set => max(0, $value); }
+            }"#,
+        );
+        assert!(matches!(
+            res.unwrap().body[..],
+            [
+                ClassMemberDeclaration::PropertyDeclaration(_),
+                ClassMemberDeclaration::PropertyDeclaration(_)
+            ]
+        ));
+    }
+
+    #[test]
+    fn promoted_hooked_parameter() {
+        let res = parse(
+            r#"class User {
+                public function __construct(
+                    public string $name { get => ucfirst($this->name); },
+                    private int $age = 0 { set { $this->age = max(0, $value); } },
+                ) {}
+            }"#,
+        );
+        assert!(matches!(
+            res.unwrap().body[..],
+            [ClassMemberDeclaration::ConstructorDeclaration(_)]
+        ));
+    }
+
+    #[test]
+    fn hooked_parameter_not_promoted_fail() {
+        let res = parse(
+            r#"class User {
+                public function __construct(string $name { get; }) {}
+            }"#,
+        );
+        assert_eq!(res, Err(()));
+
+        let res = parse(
+            r#"class User {
+                public function rename(public string $name { get; }) {}
+            }"#,
+        );
+        assert_eq!(res, Err(()));
+    }
+}
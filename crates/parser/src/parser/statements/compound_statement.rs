@@ -18,9 +18,7 @@ impl<'a> CompoundStatement<'a> {
     where
         I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
     {
-        statement_parser
-            .repeated()
-            .collect()
+        Statement::list_parser(statement_parser)
             .delimited_by(just(Token::OpenBrace), just(Token::CloseBrace))
             .map(|statements| CompoundStatement { statements })
             .labelled("CompoundStatement")
@@ -2,6 +2,8 @@ use chumsky::{input::ValueInput, span::SimpleSpan};
 use chumsky::{prelude::*, Parser};
 
 use crate::parser::atoms::const_element::ConstElement;
+use crate::parser::expressions::Expression;
+use crate::parser::keyword;
 use crate::parser::BoxedParser;
 use phprs_lexer::Token;
 
@@ -17,9 +19,11 @@ impl<'a> ConstDeclaration<'a> {
     where
         I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
     {
-        just(Token::ConstKeyword)
-            .ignore_then(ConstElement::list_parser(statement_parser))
-            .then_ignore(just(Token::Semicolon))
+        keyword(Token::ConstKeyword)
+            .ignore_then(ConstElement::list_parser(Expression::parser(
+                statement_parser,
+            )))
+            .then_ignore(Statement::terminator_parser())
             .map(Self)
             .labelled("ConstDeclaration")
     }
@@ -2,8 +2,11 @@ use chumsky::{input::ValueInput, span::SimpleSpan};
 use chumsky::{prelude::*, Parser};
 
 use crate::parser::atoms::breakout_level::BreakoutLevel;
+use crate::parser::keyword;
 use phprs_lexer::Token;
 
+use super::Statement;
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct ContinueStatement<'a>(pub Option<BreakoutLevel<'a>>);
 
@@ -12,9 +15,9 @@ impl<'a> ContinueStatement<'a> {
     where
         I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
     {
-        just(Token::ContinueKeyword)
+        keyword(Token::ContinueKeyword)
             .ignore_then(BreakoutLevel::parser().or_not())
-            .then_ignore(just(Token::Semicolon))
+            .then_ignore(Statement::terminator_parser())
             .map(ContinueStatement)
             .labelled("ContinueStatement")
     }
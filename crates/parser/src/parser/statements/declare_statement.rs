@@ -2,10 +2,11 @@ use chumsky::{input::ValueInput, span::SimpleSpan};
 use chumsky::{prelude::*, Parser};
 
 use crate::parser::atoms::literal::Literal;
+use crate::parser::keyword;
 use crate::parser::BoxedParser;
 use phprs_lexer::Token;
 
-use super::Statement;
+use super::{Statement, StatementSyntax};
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub enum DeclareDirective<'a> {
@@ -40,6 +41,7 @@ impl<'a> DeclareDirective<'a> {
 pub struct DeclareStatement<'a> {
     directive: DeclareDirective<'a>,
     statements: Vec<Statement<'a>>,
+    syntax: StatementSyntax,
 }
 
 impl<'a> DeclareStatement<'a> {
@@ -49,25 +51,71 @@ impl<'a> DeclareStatement<'a> {
     where
         I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
     {
-        let start = just(Token::DeclareKeyword)
+        let start = keyword(Token::DeclareKeyword)
             .ignore_then(just(Token::OpenParen))
             .ignore_then(DeclareDirective::parser())
             .then_ignore(just(Token::CloseParen));
 
-        let with_statement = statement_parser.clone().map(|s| vec![s]);
+        let with_statement = statement_parser
+            .clone()
+            .map(|s| (vec![s], StatementSyntax::Standard));
         let with_keywords = just(Token::Colon)
             .ignore_then(Statement::list_parser(statement_parser))
-            .then_ignore(just(Token::EndDeclareKeyword))
-            .then_ignore(just(Token::Semicolon));
+            .then_ignore(keyword(Token::EndDeclareKeyword))
+            .then_ignore(Statement::terminator_parser())
+            .map(|statements| (statements, StatementSyntax::Alternative));
 
-        let empty = just(Token::Semicolon).map(|_| vec![]);
+        let empty = Statement::terminator_parser().map(|_| (vec![], StatementSyntax::Standard));
 
         start
             .then(with_statement.or(with_keywords).or(empty))
-            .map(|(directive, statements)| DeclareStatement {
+            .map(|(directive, (statements, syntax))| DeclareStatement {
                 directive,
                 statements,
+                syntax,
             })
             .labelled("DeclareStatement")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::tokenize;
+
+    use super::*;
+
+    fn parse(src: &str) -> Result<DeclareStatement<'_>, ()> {
+        let token_stream = tokenize(src);
+
+        DeclareStatement::parser(Statement::parser().boxed())
+            .parse(token_stream)
+            .into_result()
+            .map_err(|_| ())
+    }
+
+    #[test]
+    fn empty() {
+        let res = parse(r#"declare(strict_types=1);"#);
+        assert!(matches!(
+            res,
+            Ok(DeclareStatement {
+                directive: DeclareDirective::StrictTypes(_),
+                syntax: StatementSyntax::Standard,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn alternative() {
+        let res = parse(r#"declare(ticks=1): echo 1; enddeclare;"#);
+        assert!(matches!(
+            res,
+            Ok(DeclareStatement {
+                directive: DeclareDirective::Ticks(_),
+                ref statements,
+                syntax: StatementSyntax::Alternative,
+            }) if statements.len() == 1
+        ));
+    }
+}
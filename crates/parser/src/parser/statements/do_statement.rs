@@ -2,6 +2,7 @@ use chumsky::{input::ValueInput, span::SimpleSpan};
 use chumsky::{prelude::*, Parser};
 
 use crate::parser::expressions::Expression;
+use crate::parser::keyword;
 use crate::parser::BoxedParser;
 use phprs_lexer::Token;
 
@@ -20,13 +21,13 @@ impl<'a> DoStatement<'a> {
     where
         I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
     {
-        just(Token::DoKeyword)
+        keyword(Token::DoKeyword)
             .ignore_then(statement_parser.clone())
-            .then_ignore(just(Token::WhileKeyword))
+            .then_ignore(keyword(Token::WhileKeyword))
             .then_ignore(just(Token::OpenParen))
             .then(Expression::parser(statement_parser))
             .then_ignore(just(Token::CloseParen))
-            .then_ignore(just(Token::Semicolon))
+            .then_ignore(Statement::terminator_parser())
             .map(|(statement, expression)| DoStatement {
                 statement,
                 expression,
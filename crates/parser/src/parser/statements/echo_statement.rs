@@ -4,6 +4,7 @@ use chumsky::{error::Rich, extra, input::ValueInput, Parser};
 use phprs_lexer::Token;
 
 use crate::parser::expressions::Expression;
+use crate::parser::keyword;
 use crate::parser::BoxedParser;
 
 use super::Statement;
@@ -20,9 +21,9 @@ impl<'a> EchoStatement<'a> {
     where
         I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
     {
-        just(Token::EchoKeyword)
+        keyword(Token::EchoKeyword)
             .ignore_then(Expression::list_parser(statement_parser))
-            .then_ignore(just(Token::Semicolon))
+            .then_ignore(Statement::terminator_parser())
             .map(|expression| EchoStatement {
                 expressions: expression,
             })
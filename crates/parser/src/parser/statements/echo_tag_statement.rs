@@ -0,0 +1,66 @@
+use chumsky::prelude::*;
+use chumsky::{error::Rich, extra, input::ValueInput, Parser};
+
+use phprs_lexer::Token;
+
+use crate::parser::expressions::Expression;
+use crate::parser::BoxedParser;
+
+use super::Statement;
+
+// <?= $expression ?>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct EchoTagStatement<'a> {
+    pub expressions: Vec<Expression<'a>>,
+}
+
+impl<'a> EchoTagStatement<'a> {
+    pub fn parser<I>(
+        statement_parser: BoxedParser<'a, I, Statement<'a>>,
+    ) -> impl Parser<'a, I, Self, extra::Err<Rich<'a, Token<'a>>>> + Clone
+    where
+        I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
+    {
+        just(Token::ScriptSectionStartWithEchoTag)
+            .ignore_then(Expression::list_parser(statement_parser))
+            .then_ignore(Statement::terminator_parser())
+            .map(|expressions| EchoTagStatement { expressions })
+            .labelled("EchoTagStatement")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::tokenize;
+
+    use super::*;
+
+    // NOTE: `<?=` is only lexed after inline HTML
+    fn parse(src: &str) -> Result<EchoTagStatement<'_>, ()> {
+        let tokens = tokenize(src);
+
+        just(Token::ScriptSectionEndTag)
+            .ignore_then(EchoTagStatement::parser(Statement::parser().boxed()))
+            .parse(tokens)
+            .into_result()
+            .map_err(|_| ())
+    }
+
+    #[test]
+    fn simple() {
+        let res = parse("?><?= $title;");
+        assert!(matches!(res, Ok(EchoTagStatement { expressions }) if expressions.len() == 1));
+    }
+
+    #[test]
+    fn multiple_expressions() {
+        let res = parse("?><?= $first, $second;");
+        assert!(matches!(res, Ok(EchoTagStatement { expressions }) if expressions.len() == 2));
+    }
+
+    #[test]
+    fn empty_fail() {
+        let res = parse("?><?= ;");
+        assert_eq!(res, Err(()));
+    }
+}
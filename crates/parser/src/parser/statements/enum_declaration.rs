@@ -1,9 +1,13 @@
 use chumsky::{input::ValueInput, span::SimpleSpan};
 use chumsky::{prelude::*, Parser};
 
+use crate::parser::atoms::attribute::AttributeGroup;
+use crate::parser::atoms::name::qualified_name::QualifiedName;
 use crate::parser::atoms::name::Name;
 use crate::parser::atoms::r#type::enum_type::EnumType;
 use crate::parser::class::enum_member_declaration::EnumMemberDeclaration;
+use crate::parser::expressions::Expression;
+use crate::parser::keyword;
 use crate::parser::BoxedParser;
 use phprs_lexer::Token;
 
@@ -11,12 +15,13 @@ use super::Statement;
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct EnumDeclaration<'a> {
+    attributes: Vec<AttributeGroup<'a>>,
     name: Name<'a>,
     body: Vec<EnumMemberDeclaration<'a>>,
     r#type: Option<EnumType>,
+    implements: Vec<QualifiedName<'a>>,
 }
 
-// TODO: Handle `enum Suite: string implements Colorful`
 impl<'a> EnumDeclaration<'a> {
     pub fn parser<I>(
         statement_parser: BoxedParser<'a, I, Statement<'a>>,
@@ -24,9 +29,12 @@ impl<'a> EnumDeclaration<'a> {
     where
         I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
     {
+        let expression_parser = Expression::parser(statement_parser.clone());
+        let attributes = AttributeGroup::list_parser(expression_parser.clone());
+
         let body = just(Token::OpenBrace)
             .ignore_then(
-                EnumMemberDeclaration::parser(statement_parser)
+                EnumMemberDeclaration::parser(statement_parser, expression_parser)
                     .repeated()
                     .collect(),
             )
@@ -34,11 +42,108 @@ impl<'a> EnumDeclaration<'a> {
 
         let r#type = just(Token::Colon).ignore_then(EnumType::parser()).or_not();
 
-        just(Token::EnumKeyword)
-            .ignore_then(Name::parser())
+        let implements = keyword(Token::ImplementsKeyword)
+            .ignore_then(
+                QualifiedName::parser()
+                    .separated_by(just(Token::Comma))
+                    .at_least(1)
+                    .collect(),
+            )
+            .or_not()
+            .map(|names| names.unwrap_or_default());
+
+        attributes
+            .then_ignore(keyword(Token::EnumKeyword))
+            .then(Name::parser())
             .then(r#type)
+            .then(implements)
             .then(body)
-            .map(|((name, r#type), body)| Self { name, body, r#type })
+            .map(|((((attributes, name), r#type), implements), body)| Self {
+                attributes,
+                name,
+                body,
+                r#type,
+                implements,
+            })
             .labelled("EnumDeclaration")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::tokenize;
+
+    use super::*;
+
+    fn parse(src: &str) -> Result<EnumDeclaration<'_>, ()> {
+        let tokens = tokenize(src);
+
+        EnumDeclaration::parser(Statement::parser())
+            .parse(tokens)
+            .into_result()
+            .map_err(|_| ())
+    }
+
+    #[test]
+    fn pure() {
+        let res = parse(r#"enum Status { case Active; case Inactive; }"#).unwrap();
+        assert_eq!(res.r#type, None);
+        assert_eq!(res.body.len(), 2);
+    }
+
+    #[test]
+    fn backed() {
+        let res =
+            parse(r#"enum Suit: string implements Colorful, HasLabel { case Hearts = 'H'; }"#)
+                .unwrap();
+        assert_eq!(res.r#type, Some(EnumType::String));
+        assert_eq!(res.implements.len(), 2);
+    }
+
+    #[test]
+    fn members() {
+        let res = parse(
+            r#"enum Size: int {
+                use Describable;
+
+                const FALLBACK = self::Small;
+
+                case Small = 1;
+                case Large = 2;
+
+                public static function fromLabel(string $label): self {
+                    return self::Small;
+                }
+            }"#,
+        )
+        .unwrap();
+        assert!(matches!(
+            res.body[..],
+            [
+                EnumMemberDeclaration::TraitUseClause(_),
+                EnumMemberDeclaration::ClassConstDeclaration(_),
+                EnumMemberDeclaration::CaseDeclaration(_),
+                EnumMemberDeclaration::CaseDeclaration(_),
+                EnumMemberDeclaration::MethodDeclaration(_),
+            ]
+        ));
+    }
+
+    #[test]
+    fn keyword_names() {
+        let res = parse(
+            r#"enum Mode: string {
+                case DEFAULT = 'default';
+                case List = 'list';
+
+                const PRINT = self::DEFAULT;
+
+                public function list(): array {
+                    return [self::DEFAULT, self::List];
+                }
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(res.body.len(), 4);
+    }
+}
@@ -20,9 +20,12 @@ impl<'a> ExpressionStatement<'a> {
     where
         I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
     {
-        Expression::parser(statement_parser)
-            .or_not()
-            .then_ignore(just(Token::Semicolon))
+        let expression = Expression::parser(statement_parser)
+            .map(Some)
+            .then_ignore(Statement::terminator_parser());
+        let empty = just(Token::Semicolon).map(|_| None);
+
+        choice((expression, empty))
             .map(|expression| ExpressionStatement { expression })
             .labelled("ExpressionStatement")
     }
@@ -2,10 +2,11 @@ use chumsky::{input::ValueInput, span::SimpleSpan};
 use chumsky::{prelude::*, Parser};
 
 use crate::parser::expressions::Expression;
+use crate::parser::keyword;
 use crate::parser::BoxedParser;
 use phprs_lexer::Token;
 
-use super::Statement;
+use super::{Statement, StatementSyntax};
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct ForStatement<'a> {
@@ -13,6 +14,7 @@ pub struct ForStatement<'a> {
     control: Vec<Expression<'a>>,
     end: Vec<Expression<'a>>,
     statements: Vec<Statement<'a>>,
+    syntax: StatementSyntax,
 }
 
 impl<'a> ForStatement<'a> {
@@ -25,7 +27,7 @@ impl<'a> ForStatement<'a> {
         let expression_group = Expression::parser(statement_parser.clone())
             .separated_by(just(Token::Comma))
             .collect();
-        let head = just(Token::ForKeyword)
+        let head = keyword(Token::ForKeyword)
             .ignore_then(just(Token::OpenParen))
             .ignore_then(expression_group.clone())
             .then_ignore(just(Token::Semicolon))
@@ -34,19 +36,25 @@ impl<'a> ForStatement<'a> {
             .then(expression_group)
             .then_ignore(just(Token::CloseParen));
 
-        let body1 = statement_parser.clone().map(|s| vec![s]);
+        let body1 = statement_parser
+            .clone()
+            .map(|s| (vec![s], StatementSyntax::Standard));
         let body2 = just(Token::Colon)
             .ignore_then(Statement::list_parser(statement_parser))
-            .then_ignore(just(Token::EndForKeyword))
-            .then_ignore(just(Token::Semicolon));
+            .then_ignore(keyword(Token::EndForKeyword))
+            .then_ignore(Statement::terminator_parser())
+            .map(|statements| (statements, StatementSyntax::Alternative));
 
         head.then(body1.or(body2))
-            .map(|(((initializer, control), end), statements)| ForStatement {
-                initializer,
-                control,
-                end,
-                statements,
-            })
+            .map(
+                |(((initializer, control), end), (statements, syntax))| ForStatement {
+                    initializer,
+                    control,
+                    end,
+                    statements,
+                    syntax,
+                },
+            )
             .labelled("ForStatement")
     }
 }
@@ -77,7 +85,8 @@ mod tests {
                 end: vec![],
                 statements: vec![Statement::Expression(ExpressionStatement {
                     expression: None
-                })]
+                })],
+                syntax: StatementSyntax::Standard,
             })
         );
     }
@@ -93,7 +102,8 @@ mod tests {
                 end: vec![],
                 statements: vec![Statement::Expression(ExpressionStatement {
                     expression: None
-                })]
+                })],
+                syntax: StatementSyntax::Alternative,
             })
         );
     }
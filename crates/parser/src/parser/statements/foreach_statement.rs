@@ -1,84 +1,13 @@
 use chumsky::{input::ValueInput, span::SimpleSpan};
 use chumsky::{prelude::*, Parser};
 
+use crate::parser::atoms::intrinsic::list_intrinsic::ListIntrinsic;
 use crate::parser::expressions::Expression;
-use crate::parser::variables::Variable;
+use crate::parser::keyword;
 use crate::parser::BoxedParser;
 use phprs_lexer::Token;
 
-use super::Statement;
-
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
-pub enum ListOrVariable<'a> {
-    Variable {
-        reference: bool,
-        variable: Variable<'a>,
-    },
-    List(ListIntrinsic<'a>),
-}
-
-impl<'a> ListOrVariable<'a> {
-    pub fn parser<I>(
-        statement_parser: BoxedParser<'a, I, Statement<'a>>,
-        list_instrinsic: BoxedParser<'a, I, ListIntrinsic<'a>>,
-    ) -> impl Parser<'a, I, Self, extra::Err<Rich<'a, Token<'a>>>> + Clone
-    where
-        I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
-    {
-        let variable = just(Token::Ampersand)
-            .or_not()
-            .map(|t| t.is_some())
-            .then(Variable::parser(Expression::parser(statement_parser)))
-            .map(|(reference, variable)| Self::Variable {
-                reference,
-                variable,
-            });
-
-        let list_instrinsic = list_instrinsic.map(Self::List);
-
-        variable.or(list_instrinsic)
-    }
-}
-
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
-pub enum ListIntrinsic<'a> {
-    Keyed(Vec<(Expression<'a>, ListOrVariable<'a>)>),
-    Unkeyed(Vec<ListOrVariable<'a>>),
-}
-
-impl<'a> ListIntrinsic<'a> {
-    pub fn parser<I>(
-        statement_parser: BoxedParser<'a, I, Statement<'a>>,
-    ) -> impl Parser<'a, I, Self, extra::Err<Rich<'a, Token<'a>>>> + Clone
-    where
-        I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
-    {
-        recursive(|list_intrinsic| {
-            let keyed = Expression::parser(statement_parser.clone())
-                .then_ignore(just(Token::DoubleArrow))
-                .then(ListOrVariable::parser(
-                    statement_parser.clone(),
-                    list_intrinsic.clone().boxed(),
-                ))
-                .map(|(expression, value)| (expression, value))
-                .separated_by(just(Token::Comma))
-                .allow_trailing()
-                .collect()
-                .map(Self::Keyed);
-
-            let unkeyed = ListOrVariable::parser(statement_parser, list_intrinsic.boxed())
-                .separated_by(just(Token::Comma))
-                .allow_trailing()
-                .collect()
-                .map(Self::Unkeyed);
-
-            just(Token::ListKeyword)
-                .ignore_then(just(Token::OpenParen))
-                .ignore_then(keyed.or(unkeyed))
-                .then_ignore(just(Token::CloseParen))
-        })
-    }
-}
+use super::{Statement, StatementSyntax};
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub enum ForeachValue<'a> {
@@ -105,9 +34,11 @@ impl<'a> ForeachValue<'a> {
                 expression,
             });
 
-        let list_intrinsic = ListIntrinsic::parser(statement_parser).map(Self::ListIntrinsic);
+        let list_intrinsic =
+            ListIntrinsic::parser(Expression::parser(statement_parser)).map(Self::ListIntrinsic);
 
-        expression.or(list_intrinsic)
+        // NOTE: Before `expression` so `[$a, $b]` is not parsed as an array creation
+        list_intrinsic.or(expression)
     }
 }
 
@@ -117,6 +48,7 @@ pub struct ForeachStatement<'a> {
     pub key: Option<Expression<'a>>,
     pub value: ForeachValue<'a>,
     pub body: Vec<Statement<'a>>,
+    pub syntax: StatementSyntax,
 }
 
 impl<'a> ForeachStatement<'a> {
@@ -130,10 +62,10 @@ impl<'a> ForeachStatement<'a> {
             .then_ignore(just(Token::DoubleArrow))
             .or_not();
 
-        let header = just(Token::ForeachKeyword)
+        let header = keyword(Token::ForeachKeyword)
             .ignore_then(just(Token::OpenParen))
             .ignore_then(Expression::parser(statement_parser.clone()))
-            .then_ignore(just(Token::AsKeyword))
+            .then_ignore(keyword(Token::AsKeyword))
             .then(foreach_key)
             .then(ForeachValue::parser(statement_parser.clone()))
             .then_ignore(just(Token::CloseParen))
@@ -141,19 +73,86 @@ impl<'a> ForeachStatement<'a> {
 
         let body = statement_parser
             .clone()
-            .map(|s| vec![s])
+            .map(|s| (vec![s], StatementSyntax::Standard))
             .or(just(Token::Colon)
                 .ignore_then(Statement::list_parser(statement_parser))
-                .then_ignore(just(Token::EndForEachKeyword))
-                .then_ignore(just(Token::Semicolon)));
+                .then_ignore(keyword(Token::EndForEachKeyword))
+                .then_ignore(Statement::terminator_parser())
+                .map(|statements| (statements, StatementSyntax::Alternative)));
 
         header
             .then(body)
-            .map(|((collection_name, key, value), body)| Self {
+            .map(|((collection_name, key, value), (body, syntax))| Self {
                 collection_name,
                 key,
                 value,
                 body,
+                syntax,
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::tokenize;
+
+    use super::*;
+
+    fn parse(src: &str) -> Result<ForeachStatement<'_>, ()> {
+        let token_stream = tokenize(src);
+
+        ForeachStatement::parser(Statement::parser().boxed())
+            .parse(token_stream)
+            .into_result()
+            .map_err(|_| ())
+    }
+
+    #[test]
+    fn simple() {
+        let res = parse(r#"foreach ($items as $key => $item) {}"#);
+        assert!(matches!(
+            res,
+            Ok(ForeachStatement {
+                key: Some(_),
+                syntax: StatementSyntax::Standard,
+                ..
             })
+        ));
+    }
+
+    #[test]
+    fn destructuring() {
+        let res = parse(r#"foreach ($rows as ['id' => $id, 'tags' => [, $tag]]) {}"#);
+        assert!(matches!(
+            res,
+            Ok(ForeachStatement {
+                value: ForeachValue::ListIntrinsic(ListIntrinsic::Keyed(_)),
+                ..
+            })
+        ));
+
+        let res = parse(r#"foreach ($pairs as $key => list($a, &$b)) {}"#);
+        assert!(matches!(
+            res,
+            Ok(ForeachStatement {
+                key: Some(_),
+                value: ForeachValue::ListIntrinsic(ListIntrinsic::Unkeyed(_)),
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn alternative() {
+        let res = parse(r#"foreach ($items as $item): echo $item; endforeach;"#);
+        assert!(matches!(
+            res,
+            Ok(ForeachStatement {
+                key: None,
+                ref body,
+                syntax: StatementSyntax::Alternative,
+                ..
+            }) if body.len() == 1
+        ));
     }
 }
@@ -1,11 +1,13 @@
 use chumsky::{input::ValueInput, span::SimpleSpan};
 use chumsky::{prelude::*, Parser};
 
+use crate::parser::atoms::attribute::AttributeGroup;
 use crate::parser::atoms::name::Name;
 use crate::parser::atoms::parameter::parameter_declaration::ParameterDeclaration;
 use crate::parser::atoms::parameter::variadic_parameter::VariadicParameter;
 use crate::parser::atoms::r#type::return_type::ReturnType;
 use crate::parser::expressions::Expression;
+use crate::parser::keyword;
 use crate::parser::BoxedParser;
 use phprs_lexer::Token;
 
@@ -14,6 +16,7 @@ use super::Statement;
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct FunctionDefinition<'a> {
+    attributes: Vec<AttributeGroup<'a>>,
     name: Name<'a>,
     reference: bool,
     parameters: Vec<ParameterDeclaration<'a>>,
@@ -24,7 +27,26 @@ pub struct FunctionDefinition<'a> {
 
 impl<'a> FunctionDefinition<'a> {
     pub fn parameters_parser<I>(
+        expression_parser: BoxedParser<'a, I, Expression<'a>>,
+    ) -> impl Parser<
+        'a,
+        I,
+        (Vec<ParameterDeclaration<'a>>, Option<VariadicParameter<'a>>),
+        extra::Err<Rich<'a, Token<'a>>>,
+    >
+    where
+        I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
+    {
+        Self::parameters_with(
+            ParameterDeclaration::parser(expression_parser.clone()),
+            expression_parser,
+        )
+    }
+
+    // NOTE: Parameters of a constructor, which can be promoted with hooks
+    pub fn promoted_parameters_parser<I>(
         statement_parser: BoxedParser<'a, I, Statement<'a>>,
+        expression_parser: BoxedParser<'a, I, Expression<'a>>,
     ) -> impl Parser<
         'a,
         I,
@@ -34,12 +56,30 @@ impl<'a> FunctionDefinition<'a> {
     where
         I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
     {
-        let parameters = ParameterDeclaration::parser(Expression::parser(statement_parser))
+        Self::parameters_with(
+            ParameterDeclaration::promoted_parser(statement_parser, expression_parser.clone()),
+            expression_parser,
+        )
+    }
+
+    fn parameters_with<I>(
+        parameter: impl Parser<'a, I, ParameterDeclaration<'a>, extra::Err<Rich<'a, Token<'a>>>> + Clone,
+        expression_parser: BoxedParser<'a, I, Expression<'a>>,
+    ) -> impl Parser<
+        'a,
+        I,
+        (Vec<ParameterDeclaration<'a>>, Option<VariadicParameter<'a>>),
+        extra::Err<Rich<'a, Token<'a>>>,
+    >
+    where
+        I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
+    {
+        let parameters = parameter
             .separated_by(just(Token::Comma))
             .allow_trailing()
             .collect();
 
-        let variadic = VariadicParameter::parser();
+        let variadic = VariadicParameter::parser(expression_parser);
 
         let opt = variadic
             .clone()
@@ -64,12 +104,15 @@ impl<'a> FunctionDefinition<'a> {
     where
         I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
     {
-        let start = just(Token::FunctionKeyword)
-            .ignore_then(just(Token::Ampersand).or_not().map(|t| t.is_some()))
+        let expression_parser = Expression::parser(statement_parser.clone());
+
+        let start = AttributeGroup::list_parser(expression_parser.clone())
+            .then_ignore(keyword(Token::FunctionKeyword))
+            .then(just(Token::Ampersand).or_not().map(|t| t.is_some()))
             .then(Name::parser());
 
         let parameters = just(Token::OpenParen)
-            .ignore_then(Self::parameters_parser(statement_parser.clone()))
+            .ignore_then(Self::parameters_parser(expression_parser))
             .then_ignore(just(Token::CloseParen));
 
         start
@@ -77,8 +120,12 @@ impl<'a> FunctionDefinition<'a> {
             .then(ReturnType::parser().or_not())
             .then(CompoundStatement::parser(statement_parser))
             .map(
-                |((((reference, name), (parameters, variadic)), return_type), body)| {
+                |(
+                    ((((attributes, reference), name), (parameters, variadic)), return_type),
+                    body,
+                )| {
                     FunctionDefinition {
+                        attributes,
                         name,
                         reference,
                         parameters,
@@ -3,6 +3,7 @@ use chumsky::{prelude::*, Parser};
 
 use crate::parser::atoms::name::variable_name::VariableName;
 use crate::parser::expressions::Expression;
+use crate::parser::keyword;
 use crate::parser::BoxedParser;
 use phprs_lexer::Token;
 
@@ -44,13 +45,13 @@ impl<'a> FunctionStaticDeclaration<'a> {
     where
         I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
     {
-        just(Token::StaticKeyword)
+        keyword(Token::StaticKeyword)
             .ignore_then(
                 StaticVariableDeclaration::parser(statement_parser)
                     .separated_by(just(Token::Comma))
                     .collect(),
             )
-            .then_ignore(just(Token::Semicolon))
+            .then_ignore(Statement::terminator_parser())
             .map(|variables| FunctionStaticDeclaration { variables })
             .labelled("StaticDeclaration")
     }
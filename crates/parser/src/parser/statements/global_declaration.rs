@@ -2,6 +2,7 @@ use chumsky::{input::ValueInput, span::SimpleSpan};
 use chumsky::{prelude::*, Parser};
 
 use crate::parser::expressions::Expression;
+use crate::parser::keyword;
 use crate::parser::variables::simple::SimpleVariable;
 use crate::parser::BoxedParser;
 use phprs_lexer::Token;
@@ -18,11 +19,11 @@ impl<'a> GlobalDeclaration<'a> {
     where
         I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
     {
-        just(Token::GlobalKeyword)
+        keyword(Token::GlobalKeyword)
             .ignore_then(SimpleVariable::list_parser(
                 Expression::parser(statement_parser).boxed(),
             ))
-            .then_ignore(just(Token::Semicolon))
+            .then_ignore(Statement::terminator_parser())
             .map(Self)
             .labelled("ConstDeclaration")
     }
@@ -2,8 +2,11 @@ use chumsky::{input::ValueInput, span::SimpleSpan};
 use chumsky::{prelude::*, Parser};
 
 use crate::parser::atoms::name::Name;
+use crate::parser::keyword;
 use phprs_lexer::Token;
 
+use super::Statement;
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct GotoStatement<'a>(pub Name<'a>);
 
@@ -12,9 +15,9 @@ impl<'a> GotoStatement<'a> {
     where
         I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
     {
-        just(Token::GotoKeyword)
+        keyword(Token::GotoKeyword)
             .ignore_then(Name::parser())
-            .then_ignore(just(Token::Semicolon))
+            .then_ignore(Statement::terminator_parser())
             .map(GotoStatement)
             .labelled("GotoStatement")
     }
@@ -2,13 +2,14 @@ use chumsky::prelude::*;
 use chumsky::{error::Rich, extra, input::ValueInput, Parser};
 
 use crate::parser::expressions::Expression;
+use crate::parser::keyword;
 use crate::parser::BoxedParser;
 use phprs_lexer::Token;
 
-use super::Statement;
+use super::{Statement, StatementSyntax};
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
-pub struct ElseClause<'a>(Statement<'a>);
+pub struct ElseClause<'a>(Vec<Statement<'a>>);
 
 impl<'a> ElseClause<'a> {
     pub fn parser<I>(
@@ -17,9 +18,22 @@ impl<'a> ElseClause<'a> {
     where
         I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
     {
-        just(Token::ElseKeyword)
+        keyword(Token::ElseKeyword)
             .ignore_then(statement_parser)
             .labelled("ElseClause")
+            .map(|statement| ElseClause(vec![statement]))
+    }
+
+    pub fn alternative_parser<I>(
+        statement_parser: BoxedParser<'a, I, Statement<'a>>,
+    ) -> impl Parser<'a, I, Self, extra::Err<Rich<'a, Token<'a>>>> + Clone
+    where
+        I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
+    {
+        keyword(Token::ElseKeyword)
+            .ignore_then(just(Token::Colon))
+            .ignore_then(Statement::list_parser(statement_parser))
+            .labelled("ElseClause")
             .map(ElseClause)
     }
 }
@@ -27,7 +41,7 @@ impl<'a> ElseClause<'a> {
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct ElseIfClause<'a> {
     expression: Expression<'a>,
-    statement: Statement<'a>,
+    statements: Vec<Statement<'a>>,
 }
 
 impl<'a> ElseIfClause<'a> {
@@ -41,12 +55,32 @@ impl<'a> ElseIfClause<'a> {
         let expr = Expression::parser(statement_parser.clone())
             .delimited_by(just(Token::OpenParen), just(Token::CloseParen));
 
-        just(Token::ElseIfKeyword)
+        keyword(Token::ElseIfKeyword)
             .ignore_then(expr)
             .then(statement_parser)
             .map(|(expression, statement)| ElseIfClause {
                 expression,
-                statement,
+                statements: vec![statement],
+            })
+            .labelled("ElseIf clause")
+    }
+
+    pub fn alternative_parser<I>(
+        statement_parser: BoxedParser<'a, I, Statement<'a>>,
+    ) -> impl Parser<'a, I, Self, extra::Err<Rich<'a, Token<'a>>>> + Clone
+    where
+        I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
+    {
+        let expr = Expression::parser(statement_parser.clone())
+            .delimited_by(just(Token::OpenParen), just(Token::CloseParen));
+
+        keyword(Token::ElseIfKeyword)
+            .ignore_then(expr)
+            .then_ignore(just(Token::Colon))
+            .then(Statement::list_parser(statement_parser))
+            .map(|(expression, statements)| ElseIfClause {
+                expression,
+                statements,
             })
             .labelled("ElseIf clause")
     }
@@ -55,13 +89,13 @@ impl<'a> ElseIfClause<'a> {
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct IfStatement<'a> {
     expression: Expression<'a>,
-    statement: Statement<'a>,
+    statements: Vec<Statement<'a>>,
     elseif_clauses: Vec<ElseIfClause<'a>>,
     else_clause: Option<ElseClause<'a>>,
+    syntax: StatementSyntax,
 }
 
 impl<'a> IfStatement<'a> {
-    // TODO: Manage with keywords endif
     pub fn parser<I>(
         statement_parser: BoxedParser<'a, I, Statement<'a>>,
     ) -> impl Parser<'a, I, Self, extra::Err<Rich<'a, Token<'a>>>> + Clone
@@ -71,24 +105,43 @@ impl<'a> IfStatement<'a> {
         let expr = Expression::parser(statement_parser.clone())
             .delimited_by(just(Token::OpenParen), just(Token::CloseParen));
 
-        let if_clause = just(Token::IfKeyword)
-            .ignore_then(expr.clone())
-            .then(statement_parser.clone())
-            .labelled("If clause");
+        let head = keyword(Token::IfKeyword).ignore_then(expr);
 
-        if_clause
+        let standard = head
+            .clone()
+            .then(statement_parser.clone().map(|s| vec![s]))
+            .labelled("If clause")
             .then(
                 ElseIfClause::parser(statement_parser.clone())
                     .repeated()
                     .collect(),
             )
-            .then(ElseClause::parser(statement_parser).or_not())
+            .then(ElseClause::parser(statement_parser.clone()).or_not())
+            .map(|clauses| (clauses, StatementSyntax::Standard));
+
+        let alternative = head
+            .then_ignore(just(Token::Colon))
+            .then(Statement::list_parser(statement_parser.clone()))
+            .labelled("If clause")
+            .then(
+                ElseIfClause::alternative_parser(statement_parser.clone())
+                    .repeated()
+                    .collect(),
+            )
+            .then(ElseClause::alternative_parser(statement_parser).or_not())
+            .then_ignore(keyword(Token::EndIfKeyword))
+            .then_ignore(Statement::terminator_parser())
+            .map(|clauses| (clauses, StatementSyntax::Alternative));
+
+        standard
+            .or(alternative)
             .map(
-                |(((expression, statement), elseif_clauses), else_clause)| IfStatement {
+                |((((expression, statements), elseif_clauses), else_clause), syntax)| IfStatement {
                     expression,
-                    statement,
+                    statements,
                     else_clause,
                     elseif_clauses,
+                    syntax,
                 },
             )
             .labelled("IfStatement")
@@ -125,7 +178,9 @@ mod tests {
                 expression: Expression::Primary(PrimaryExpression::ReservedWord(
                     ReservedWordExpression::True
                 )),
-                statement: Statement::Compound(CompoundStatement { statements: vec![] })
+                statements: vec![Statement::Compound(CompoundStatement {
+                    statements: vec![]
+                })]
             })
         );
     }
@@ -144,9 +199,9 @@ mod tests {
         let res = parse_else_clause(r#"else {}"#);
         assert_eq!(
             res,
-            Ok(ElseClause(Statement::Compound(CompoundStatement {
+            Ok(ElseClause(vec![Statement::Compound(CompoundStatement {
                 statements: vec![]
-            })))
+            })]))
         );
     }
 
@@ -168,17 +223,49 @@ mod tests {
                 expression: Expression::Primary(PrimaryExpression::ReservedWord(
                     ReservedWordExpression::True
                 )),
-                statement: Statement::Compound(CompoundStatement { statements: vec![] }),
+                statements: vec![Statement::Compound(CompoundStatement {
+                    statements: vec![]
+                })],
                 elseif_clauses: vec![ElseIfClause {
                     expression: Expression::Primary(PrimaryExpression::ReservedWord(
                         ReservedWordExpression::False
                     )),
-                    statement: Statement::Compound(CompoundStatement { statements: vec![] })
+                    statements: vec![Statement::Compound(CompoundStatement {
+                        statements: vec![]
+                    })]
                 }],
-                else_clause: Some(ElseClause(Statement::Compound(CompoundStatement {
+                else_clause: Some(ElseClause(vec![Statement::Compound(CompoundStatement {
                     statements: vec![]
-                })))
+                })])),
+                syntax: StatementSyntax::Standard,
             })
         );
     }
+
+    #[test]
+    fn alternative() {
+        let res = parse(r#"if (true): echo 1; echo 2; elseif (false): else: endif;"#);
+        assert!(matches!(
+            res,
+            Ok(IfStatement {
+                ref statements,
+                ref elseif_clauses,
+                else_clause: Some(ElseClause(ref else_statements)),
+                syntax: StatementSyntax::Alternative,
+                ..
+            }) if statements.len() == 2 && elseif_clauses.len() == 1 && else_statements.is_empty()
+        ));
+    }
+
+    #[test]
+    fn alternative_missing_endif_fail() {
+        let res = parse(r#"if (true): echo 1;"#);
+        assert_eq!(res, Err(()));
+    }
+
+    #[test]
+    fn mixed_syntax_fail() {
+        let res = parse(r#"if (true): echo 1; else { echo 2; } endif;"#);
+        assert_eq!(res, Err(()));
+    }
 }
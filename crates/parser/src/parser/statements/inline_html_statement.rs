@@ -0,0 +1,68 @@
+use chumsky::prelude::*;
+use chumsky::{error::Rich, extra, input::ValueInput, Parser};
+
+use phprs_lexer::Token;
+
+// Text outside of the PHP tags: `?> <p>Hello</p> <?php`
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct InlineHtmlStatement<'a> {
+    pub text: &'a str,
+}
+
+impl<'a> InlineHtmlStatement<'a> {
+    pub fn parser<I>() -> impl Parser<'a, I, Self, extra::Err<Rich<'a, Token<'a>>>> + Clone
+    where
+        I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
+    {
+        let text = select! {
+            Token::InlineHtml(text) => text,
+        };
+
+        // NOTE: When followed by `<?=` the start tag is part of the `EchoTagStatement`
+        just(Token::ScriptSectionEndTag)
+            .or_not()
+            .ignore_then(text)
+            .then_ignore(just(Token::ScriptSectionStartTag).or_not())
+            .map(|text| Self { text })
+            .labelled("InlineHtmlStatement")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::tokenize;
+
+    use super::*;
+
+    fn parse(src: &str) -> Result<InlineHtmlStatement<'_>, ()> {
+        let tokens = tokenize(src);
+
+        InlineHtmlStatement::parser()
+            .parse(tokens)
+            .into_result()
+            .map_err(|_| ())
+    }
+
+    #[test]
+    fn simple() {
+        let res = parse("?>\n<p>Hello</p>\n<?php ");
+        assert_eq!(
+            res,
+            Ok(InlineHtmlStatement {
+                text: "<p>Hello</p>\n"
+            })
+        );
+    }
+
+    #[test]
+    fn empty_fail() {
+        let res = parse("?><?php ");
+        assert_eq!(res, Err(()));
+    }
+
+    #[test]
+    fn end_of_file() {
+        let res = parse("?>\n</html>");
+        assert_eq!(res, Ok(InlineHtmlStatement { text: "</html>" }));
+    }
+}
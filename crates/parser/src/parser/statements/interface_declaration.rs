@@ -1,6 +1,9 @@
+use crate::parser::atoms::attribute::AttributeGroup;
 use crate::parser::atoms::name::qualified_name::QualifiedName;
 use crate::parser::atoms::name::Name;
+use crate::parser::expressions::Expression;
 use crate::parser::interface::interface_member_declaration::InterfaceMemberDeclaration;
+use crate::parser::keyword;
 use crate::parser::BoxedParser;
 use chumsky::{input::ValueInput, span::SimpleSpan};
 use chumsky::{prelude::*, Parser};
@@ -10,6 +13,7 @@ use super::Statement;
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct InterfaceDeclaration<'a> {
+    attributes: Vec<AttributeGroup<'a>>,
     name: Name<'a>,
     extends: Vec<QualifiedName<'a>>,
     body: Vec<InterfaceMemberDeclaration<'a>>,
@@ -22,7 +26,10 @@ impl<'a> InterfaceDeclaration<'a> {
     where
         I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
     {
-        let extends = just(Token::ExtendsKeyword)
+        let expression_parser = Expression::parser(statement_parser.clone());
+        let attributes = AttributeGroup::list_parser(expression_parser.clone());
+
+        let extends = keyword(Token::ExtendsKeyword)
             .ignore_then(
                 QualifiedName::parser()
                     .separated_by(just(Token::Comma))
@@ -34,17 +41,19 @@ impl<'a> InterfaceDeclaration<'a> {
 
         let body = just(Token::OpenBrace)
             .ignore_then(
-                InterfaceMemberDeclaration::parser(statement_parser)
+                InterfaceMemberDeclaration::parser(statement_parser, expression_parser)
                     .repeated()
                     .collect(),
             )
             .then_ignore(just(Token::CloseBrace));
 
-        just(Token::InterfaceKeyword)
-            .ignore_then(Name::parser())
+        attributes
+            .then_ignore(keyword(Token::InterfaceKeyword))
+            .then(Name::parser())
             .then(extends)
             .then(body)
-            .map(|((name, extends), body)| Self {
+            .map(|(((attributes, name), extends), body)| Self {
+                attributes,
                 name,
                 extends,
                 body,
@@ -52,3 +61,63 @@ impl<'a> InterfaceDeclaration<'a> {
             .labelled("InterfaceDeclaration")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::tokenize;
+
+    use super::*;
+
+    fn parse(src: &str) -> Result<InterfaceDeclaration<'_>, ()> {
+        let tokens = tokenize(src);
+
+        InterfaceDeclaration::parser(Statement::parser())
+            .parse(tokens)
+            .into_result()
+            .map_err(|_| ())
+    }
+
+    #[test]
+    fn members() {
+        let res = parse(
+            r#"interface HasName extends Named {
+                const PREFIX = 'name';
+                public function rename(string $name): void;
+            }"#,
+        )
+        .unwrap();
+        assert!(matches!(
+            res.body[..],
+            [
+                InterfaceMemberDeclaration::ClassConstDeclaration(_),
+                InterfaceMemberDeclaration::MethodDeclaration(_)
+            ]
+        ));
+    }
+
+    #[test]
+    fn hooked_property() {
+        let res = parse(
+            r#"interface HasName {
+                public string $name { get; }
+                public string $label { get; set; }
+            }"#,
+        )
+        .unwrap();
+        assert!(matches!(
+            res.body[..],
+            [
+                InterfaceMemberDeclaration::PropertyDeclaration(_),
+                InterfaceMemberDeclaration::PropertyDeclaration(_)
+            ]
+        ));
+    }
+
+    #[test]
+    fn property_without_hooks_fail() {
+        assert_eq!(
+            parse(r#"interface HasName { public string $name; }"#),
+            Err(())
+        );
+    }
+}
@@ -1,9 +1,9 @@
-use chumsky::prelude::*;
 use chumsky::{error::Rich, extra, input::ValueInput, span::SimpleSpan, Parser};
 
 use phprs_lexer::Token;
 
 use crate::parser::atoms::name::namespace_name::NamespaceName;
+use crate::parser::keyword;
 use crate::parser::BoxedParser;
 
 use super::compound_statement::CompoundStatement;
@@ -25,11 +25,11 @@ impl<'a> NamespaceDefinition<'a> {
     where
         I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
     {
-        let name = just(Token::NamespaceKeyword)
+        let name = keyword(Token::NamespaceKeyword)
             .ignore_then(NamespaceName::parser())
-            .then_ignore(just(Token::Semicolon))
+            .then_ignore(Statement::terminator_parser())
             .map(Self::Name);
-        let compound = just(Token::NamespaceKeyword)
+        let compound = keyword(Token::NamespaceKeyword)
             .ignore_then(NamespaceName::parser().or_not())
             .then(CompoundStatement::parser(statement_parser))
             .map(|(name, statement)| Self::Compound { name, statement });
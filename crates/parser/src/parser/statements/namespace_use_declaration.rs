@@ -1,9 +1,11 @@
-use chumsky::prelude::*;
 use chumsky::{error::Rich, extra, input::ValueInput, span::SimpleSpan, Parser};
 
 use phprs_lexer::Token;
 
 use crate::parser::atoms::name::namespace_name::NamespaceName;
+use crate::parser::keyword;
+
+use super::Statement;
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct NamespaceUseDeclaration<'a> {
@@ -15,9 +17,9 @@ impl<'a> NamespaceUseDeclaration<'a> {
     where
         I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
     {
-        just(Token::UseKeyword)
+        keyword(Token::UseKeyword)
             .ignore_then(NamespaceName::parser())
-            .then_ignore(just(Token::Semicolon))
+            .then_ignore(Statement::terminator_parser())
             .map(|namespace_name| NamespaceUseDeclaration { namespace_name })
             .labelled("NamespaceUseDeclaration")
     }
@@ -4,9 +4,10 @@ use chumsky::{error::Rich, extra, input::ValueInput, span::SimpleSpan, Parser};
 use phprs_lexer::Token;
 
 use crate::parser::expressions::Expression;
+use crate::parser::keyword;
 use crate::parser::BoxedParser;
 
-use super::Statement;
+use super::{Statement, StatementSyntax};
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct CaseStatement<'a> {
@@ -25,10 +26,10 @@ impl<'a> CaseStatement<'a> {
     {
         let terminator = choice((just(Token::Colon), just(Token::Semicolon)));
 
-        let head = just(Token::CaseKeyword)
+        let head = keyword(Token::CaseKeyword)
             .ignore_then(Expression::parser(statement_parser.clone()))
             .map(Some);
-        let default_head = just(Token::DefaultKeyword).map(|_| None);
+        let default_head = keyword(Token::DefaultKeyword).map(|_| None);
 
         head.or(default_head)
             .then(terminator)
@@ -45,6 +46,7 @@ impl<'a> CaseStatement<'a> {
 pub struct SwitchStatement<'a> {
     expression: Expression<'a>,
     cases: Vec<CaseStatement<'a>>,
+    syntax: StatementSyntax,
 }
 
 impl<'a> SwitchStatement<'a> {
@@ -57,21 +59,28 @@ impl<'a> SwitchStatement<'a> {
         let expr = Expression::parser(statement_parser.clone())
             .delimited_by(just(Token::OpenParen), just(Token::CloseParen));
 
-        let switch = just(Token::SwitchKeyword).ignore_then(expr);
+        let switch = keyword(Token::SwitchKeyword).ignore_then(expr);
         let body1 = just(Token::OpenBrace)
             .ignore_then(
                 CaseStatement::parser(statement_parser.clone())
                     .repeated()
                     .collect(),
             )
-            .then_ignore(just(Token::CloseBrace));
+            .then_ignore(just(Token::CloseBrace))
+            .map(|cases| (cases, StatementSyntax::Standard));
         let body2 = just(Token::Colon)
             .ignore_then(CaseStatement::parser(statement_parser).repeated().collect())
-            .then_ignore(just(Token::EndSwitchKeyword));
+            .then_ignore(keyword(Token::EndSwitchKeyword))
+            .then_ignore(Statement::terminator_parser())
+            .map(|cases| (cases, StatementSyntax::Alternative));
 
         switch
             .then(body1.or(body2))
-            .map(|(expression, cases)| SwitchStatement { expression, cases })
+            .map(|(expression, (cases, syntax))| SwitchStatement {
+                expression,
+                cases,
+                syntax,
+            })
             .labelled("SwitchStatement")
     }
 }
@@ -163,7 +172,8 @@ mod tests {
                 expression: Expression::Primary(PrimaryExpression::Literal(LiteralExpression(
                     Literal::Integer(IntegerLiteral("5"))
                 ))),
-                cases: vec![]
+                cases: vec![],
+                syntax: StatementSyntax::Standard,
             })
         );
     }
@@ -199,8 +209,28 @@ mod tests {
                             expression: None
                         })]
                     }
-                ]
+                ],
+                syntax: StatementSyntax::Standard,
             })
         );
     }
+
+    #[test]
+    fn alternative() {
+        let res = parse(r#"switch(5): case 5: echo 5; default: endswitch;"#);
+        assert!(matches!(
+            res,
+            Ok(SwitchStatement {
+                ref cases,
+                syntax: StatementSyntax::Alternative,
+                ..
+            }) if cases.len() == 2
+        ));
+    }
+
+    #[test]
+    fn alternative_missing_semicolon_fail() {
+        let res = parse(r#"switch(5): endswitch"#);
+        assert_eq!(res, Err(()));
+    }
 }
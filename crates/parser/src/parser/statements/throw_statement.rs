@@ -2,6 +2,7 @@ use chumsky::{input::ValueInput, span::SimpleSpan};
 use chumsky::{prelude::*, Parser};
 
 use crate::parser::expressions::Expression;
+use crate::parser::keyword;
 use crate::parser::BoxedParser;
 use phprs_lexer::Token;
 
@@ -17,9 +18,9 @@ impl<'a> ThrowStatement<'a> {
     where
         I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
     {
-        just(Token::ThrowKeyword)
+        keyword(Token::ThrowKeyword)
             .ignore_then(Expression::parser(statement_parser).or_not())
-            .then_ignore(just(Token::Semicolon))
+            .then_ignore(Statement::terminator_parser())
             .map(ThrowStatement)
             .labelled("ReturnStatement")
     }
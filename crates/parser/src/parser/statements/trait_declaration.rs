@@ -1,5 +1,8 @@
+use crate::parser::atoms::attribute::AttributeGroup;
 use crate::parser::atoms::name::Name;
+use crate::parser::expressions::Expression;
 use crate::parser::interface::trait_member_declaration::TraitMemberDeclaration;
+use crate::parser::keyword;
 use crate::parser::BoxedParser;
 use chumsky::{input::ValueInput, span::SimpleSpan};
 use chumsky::{prelude::*, Parser};
@@ -9,6 +12,7 @@ use super::Statement;
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct TraitDeclaration<'a> {
+    attributes: Vec<AttributeGroup<'a>>,
     name: Name<'a>,
     body: Vec<TraitMemberDeclaration<'a>>,
 }
@@ -20,18 +24,26 @@ impl<'a> TraitDeclaration<'a> {
     where
         I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
     {
+        let expression_parser = Expression::parser(statement_parser.clone());
+        let attributes = AttributeGroup::list_parser(expression_parser.clone());
+
         let body = just(Token::OpenBrace)
             .ignore_then(
-                TraitMemberDeclaration::parser(statement_parser)
+                TraitMemberDeclaration::parser(statement_parser, expression_parser)
                     .repeated()
                     .collect(),
             )
             .then_ignore(just(Token::CloseBrace));
 
-        just(Token::TraitKeyword)
-            .ignore_then(Name::parser())
+        attributes
+            .then_ignore(keyword(Token::TraitKeyword))
+            .then(Name::parser())
             .then(body)
-            .map(|(name, body)| Self { name, body })
+            .map(|((attributes, name), body)| Self {
+                attributes,
+                name,
+                body,
+            })
             .labelled("InterfaceDeclaration")
     }
 }
@@ -3,6 +3,7 @@ use chumsky::{prelude::*, Parser};
 
 use crate::parser::atoms::name::qualified_name::QualifiedName;
 use crate::parser::atoms::name::variable_name::VariableName;
+use crate::parser::keyword;
 use crate::parser::BoxedParser;
 use phprs_lexer::Token;
 
@@ -23,7 +24,7 @@ impl<'a> CatchClause<'a> {
     where
         I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
     {
-        just(Token::CatchKeyword)
+        keyword(Token::CatchKeyword)
             .ignore_then(just(Token::OpenParen))
             .ignore_then(
                 QualifiedName::parser()
@@ -59,11 +60,11 @@ impl<'a> TryStatement<'a> {
             .repeated()
             .collect();
 
-        let finally_clause = just(Token::FinallyKeyword)
+        let finally_clause = keyword(Token::FinallyKeyword)
             .ignore_then(CompoundStatement::parser(statement_parser.clone()))
             .or_not();
 
-        just(Token::TryKeyword)
+        keyword(Token::TryKeyword)
             .ignore_then(CompoundStatement::parser(statement_parser))
             .then(catch_clauses)
             .then(finally_clause)
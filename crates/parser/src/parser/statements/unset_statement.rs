@@ -2,6 +2,7 @@ use chumsky::{input::ValueInput, span::SimpleSpan};
 use chumsky::{prelude::*, Parser};
 
 use crate::parser::expressions::Expression;
+use crate::parser::keyword;
 use crate::parser::variables::Variable;
 use crate::parser::BoxedParser;
 use phprs_lexer::Token;
@@ -20,11 +21,11 @@ impl<'a> UnsetStatement<'a> {
     where
         I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
     {
-        just(Token::UnsetKeyword)
+        keyword(Token::UnsetKeyword)
             .ignore_then(just(Token::OpenParen))
             .ignore_then(Variable::list_parser(Expression::parser(statement_parser)))
             .then_ignore(just(Token::CloseParen))
-            .then_ignore(just(Token::Semicolon))
+            .then_ignore(Statement::terminator_parser())
             .map(|variables| UnsetStatement { variables })
             .labelled("UnsetStatement")
     }
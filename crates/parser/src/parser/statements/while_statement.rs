@@ -4,14 +4,16 @@ use chumsky::{error::Rich, extra, input::ValueInput, span::SimpleSpan, Parser};
 use phprs_lexer::Token;
 
 use crate::parser::expressions::Expression;
+use crate::parser::keyword;
 use crate::parser::BoxedParser;
 
-use super::Statement;
+use super::{Statement, StatementSyntax};
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct WhileStatement<'a> {
     expression: Expression<'a>,
     statements: Vec<Statement<'a>>,
+    syntax: StatementSyntax,
 }
 
 impl<'a> WhileStatement<'a> {
@@ -21,21 +23,25 @@ impl<'a> WhileStatement<'a> {
     where
         I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
     {
-        let head = just(Token::WhileKeyword).ignore_then(
+        let head = keyword(Token::WhileKeyword).ignore_then(
             Expression::parser(statement_parser.clone())
                 .delimited_by(just(Token::OpenParen), just(Token::CloseParen)),
         );
 
-        let body1 = statement_parser.clone().map(|s| vec![s]);
+        let body1 = statement_parser
+            .clone()
+            .map(|s| (vec![s], StatementSyntax::Standard));
         let body2 = just(Token::Colon)
             .ignore_then(Statement::list_parser(statement_parser))
-            .then_ignore(just(Token::EndWhileKeyword))
-            .then_ignore(just(Token::Semicolon));
+            .then_ignore(keyword(Token::EndWhileKeyword))
+            .then_ignore(Statement::terminator_parser())
+            .map(|statements| (statements, StatementSyntax::Alternative));
 
         head.then(body1.or(body2))
-            .map(|(expression, statements)| WhileStatement {
+            .map(|(expression, (statements, syntax))| WhileStatement {
                 expression,
                 statements,
+                syntax,
             })
             .labelled("WhileStatement")
     }
@@ -74,7 +80,8 @@ mod tests {
                 ))),
                 statements: vec![Statement::Expression(ExpressionStatement {
                     expression: None
-                })]
+                })],
+                syntax: StatementSyntax::Standard,
             })
         );
     }
@@ -90,7 +97,8 @@ mod tests {
                 ))),
                 statements: vec![Statement::Expression(ExpressionStatement {
                     expression: None
-                })]
+                })],
+                syntax: StatementSyntax::Alternative,
             })
         );
     }
@@ -2,6 +2,7 @@ pub mod callable;
 pub mod new_variable;
 pub mod simple;
 
+use crate::parser::keyword;
 use crate::parser::BoxedParser;
 use callable::CallableVariable;
 use chumsky::{input::ValueInput, prelude::*};
@@ -9,14 +10,20 @@ use phprs_lexer::Token;
 use simple::SimpleVariable;
 
 use super::{
-    atoms::{member_name::MemberName, scope_resolution_qualifier::ScopeResolutionQualifier},
+    atoms::{
+        member_name::MemberName, name::Name, scope_resolution_qualifier::ScopeResolutionQualifier,
+    },
     expressions::{
         argument_expression::ArgumentExpression,
-        dereferencable_expression::DereferencableExpression, Expression,
+        callable_creation_expression::CallableCreationExpression,
+        class_constant_access_expression::ClassConstantAccessExpression,
+        dereferencable_expression::DereferencableExpression,
+        member_access_expression::MemberAccessExpression,
+        scoped_call_expression::ScopedCallExpression, Expression,
     },
 };
 
-type PostfixOp<'a> = Box<dyn FnOnce(Variable<'a>) -> Variable<'a> + 'a>;
+type PostfixOp<'a> = Box<dyn FnOnce(DereferencableExpression<'a>) -> Variable<'a> + 'a>;
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub enum Variable<'a> {
@@ -24,8 +31,14 @@ pub enum Variable<'a> {
 
     // TODO: foldl makes it harder to navigate the AST tree
     ScopedPropertryAccessExpression(ScopeResolutionQualifier<'a>, SimpleVariable<'a>), // TODO
-    MemberAccessExpression(DereferencableExpression<'a>, MemberName<'a>),
+    MemberAccessExpression(MemberAccessExpression<'a>),
     MemberCallExpression(DereferencableExpression<'a>, Vec<ArgumentExpression<'a>>),
+    // `$fn(...)` or `$object->method(...)`
+    CallableCreationExpression(DereferencableExpression<'a>),
+    // `$array['key']` or `$array[]`
+    SubscriptExpression(DereferencableExpression<'a>, Option<Expression<'a>>),
+    // `$object::CONSTANT` or `$object::class`
+    ClassConstantAccessExpression(ClassConstantAccessExpression<'a>),
 }
 
 impl<'a> Variable<'a> {
@@ -35,30 +48,94 @@ impl<'a> Variable<'a> {
     where
         I: ValueInput<'a, Token = Token<'a>, Span = SimpleSpan>,
     {
-        let member_access_postfix = just(Token::Arrow)
-            .ignore_then(MemberName::parser(expression_parser.clone()))
-            .map(|member| {
-                Box::new(move |prev: Variable<'a>| {
-                    Variable::MemberAccessExpression(
-                        DereferencableExpression::Variable(Box::new(prev)),
-                        member,
-                    )
-                }) as PostfixOp<'a>
-            });
+        let member_access_postfix = choice((
+            just(Token::Arrow).to(false),
+            just(Token::QuestionArrow).to(true),
+        ))
+        .then(MemberName::parser(expression_parser.clone()))
+        .map(|(nullsafe, member)| {
+            Box::new(move |expression| {
+                Variable::MemberAccessExpression(MemberAccessExpression {
+                    expression,
+                    member,
+                    nullsafe,
+                })
+            }) as PostfixOp<'a>
+        });
 
         let member_call_postfix = just(Token::OpenParen)
             .ignore_then(ArgumentExpression::list_parser(expression_parser.clone()))
             .then_ignore(just(Token::CloseParen))
             .map(|arguments| {
-                Box::new(move |prev: Variable<'a>| {
-                    Variable::MemberCallExpression(
-                        DereferencableExpression::Variable(Box::new(prev)),
-                        arguments,
-                    )
+                Box::new(move |prev| Variable::MemberCallExpression(prev, arguments))
+                    as PostfixOp<'a>
+            });
+
+        let callable_creation_postfix = CallableCreationExpression::arguments_parser()
+            .map(|_| Box::new(Variable::CallableCreationExpression) as PostfixOp<'a>);
+
+        let subscript_postfix = just(Token::OpenBracket)
+            .ignore_then(expression_parser.clone().or_not())
+            .then_ignore(just(Token::CloseBracket))
+            .map(|offset| {
+                Box::new(move |prev| Variable::SubscriptExpression(prev, offset)) as PostfixOp<'a>
+            });
+
+        let scope = |prev| ScopeResolutionQualifier::DereferencableExpression(Box::new(prev));
+
+        let scoped_call_postfix = just(Token::ColonColon)
+            .ignore_then(MemberName::parser(expression_parser.clone()))
+            .then_ignore(just(Token::OpenParen))
+            .then(ArgumentExpression::list_parser(expression_parser.clone()))
+            .then_ignore(just(Token::CloseParen))
+            .map(move |(member, arguments)| {
+                Box::new(move |prev| {
+                    Variable::Callable(CallableVariable::ScopedCallExpression(
+                        ScopedCallExpression {
+                            scope: scope(prev),
+                            member,
+                            arguments,
+                        },
+                    ))
                 }) as PostfixOp<'a>
             });
 
-        choice((member_access_postfix, member_call_postfix))
+        let scoped_property_access_postfix = just(Token::ColonColon)
+            .ignore_then(SimpleVariable::parser(expression_parser))
+            .map(move |property| {
+                Box::new(move |prev| {
+                    Variable::ScopedPropertryAccessExpression(scope(prev), property)
+                }) as PostfixOp<'a>
+            });
+
+        let class_constant_access_postfix = just(Token::ColonColon)
+            .ignore_then(choice((
+                keyword(Token::ClassKeyword).to(None),
+                Name::identifier_parser().map(Some),
+            )))
+            .map(move |name| {
+                Box::new(move |prev| {
+                    Variable::ClassConstantAccessExpression(match name {
+                        Some(name) => ClassConstantAccessExpression::Name {
+                            scope: scope(prev),
+                            name,
+                        },
+                        None => ClassConstantAccessExpression::Class { scope: scope(prev) },
+                    })
+                }) as PostfixOp<'a>
+            });
+
+        choice((
+            member_access_postfix,
+            callable_creation_postfix,
+            member_call_postfix,
+            subscript_postfix,
+            scoped_call_postfix,
+            scoped_property_access_postfix,
+            // NOTE: After `scoped_call_postfix` so `::bar()` is not taken as a constant
+            class_constant_access_postfix,
+        ))
+        .boxed()
     }
 
     pub fn parser<I>(
@@ -80,8 +157,11 @@ impl<'a> Variable<'a> {
 
         let postfix = Self::postfix_parser(expression_parser);
 
-        base.foldl(postfix.repeated(), |a, b| b(a))
-            .labelled("Variable")
+        base.foldl(postfix.repeated(), |a, b| {
+            b(DereferencableExpression::Variable(Box::new(a)))
+        })
+        .labelled("Variable")
+        .boxed()
     }
 
     pub fn list_parser<I>(
@@ -122,7 +202,150 @@ mod tests {
     #[test]
     fn method_access() {
         let res = parse(r#"$test->hello"#);
-        assert!(matches!(res, Ok(Variable::MemberAccessExpression(_, _))));
-        assert!(matches!(res, Ok(Variable::MemberAccessExpression(_, _))));
+        assert!(matches!(res, Ok(Variable::MemberAccessExpression(_))));
+        assert!(matches!(
+            res,
+            Ok(Variable::MemberAccessExpression(MemberAccessExpression {
+                nullsafe: false,
+                ..
+            }))
+        ));
+    }
+
+    #[test]
+    fn keyword_member() {
+        let res = parse(r#"$query->list()"#);
+        assert!(matches!(res, Ok(Variable::MemberCallExpression(_, _))));
+
+        let res = parse(r#"$this?->class"#);
+        assert!(matches!(
+            res,
+            Ok(Variable::MemberAccessExpression(MemberAccessExpression {
+                nullsafe: true,
+                ..
+            }))
+        ));
+
+        let res = parse(r#"$o->Class"#);
+        assert!(matches!(
+            res,
+            Ok(Variable::MemberAccessExpression(MemberAccessExpression {
+                member: MemberName::Name(Name("Class")),
+                ..
+            }))
+        ));
+    }
+
+    #[test]
+    fn callable_creation() {
+        let res = parse(r#"$object->method(...)"#);
+        let Ok(Variable::CallableCreationExpression(DereferencableExpression::Variable(callee))) =
+            res
+        else {
+            panic!("expected a callable creation, got {res:?}");
+        };
+        assert!(matches!(*callee, Variable::MemberAccessExpression(_)));
+
+        let res = parse(r#"$fn(...)"#);
+        assert!(matches!(res, Ok(Variable::CallableCreationExpression(_))));
+    }
+
+    #[test]
+    fn static_call_chain() {
+        let res = parse(r#"Foo::create()->build(...)"#);
+        assert!(matches!(res, Ok(Variable::CallableCreationExpression(_))));
+    }
+
+    #[test]
+    fn nullsafe_member_access() {
+        let res = parse(r#"$user?->address"#);
+        assert!(matches!(
+            res,
+            Ok(Variable::MemberAccessExpression(MemberAccessExpression {
+                expression: DereferencableExpression::Variable(_),
+                nullsafe: true,
+                ..
+            }))
+        ));
+    }
+
+    #[test]
+    fn nullsafe_chain() {
+        let res = parse(r#"$user?->getAddress()?->city"#);
+        let Ok(Variable::MemberAccessExpression(MemberAccessExpression {
+            expression: DereferencableExpression::Variable(call),
+            nullsafe: true,
+            ..
+        })) = res
+        else {
+            panic!("expected a nullsafe member access, got {res:?}");
+        };
+        assert!(matches!(
+            *call,
+            Variable::MemberCallExpression(DereferencableExpression::Variable(ref callee), _)
+                if matches!(
+                    **callee,
+                    Variable::MemberAccessExpression(MemberAccessExpression { nullsafe: true, .. })
+                )
+        ));
+    }
+
+    #[test]
+    fn subscript() {
+        let res = parse(r#"$items['key'][0]"#);
+        let Ok(Variable::SubscriptExpression(DereferencableExpression::Variable(array), Some(_))) =
+            res
+        else {
+            panic!("expected a subscript, got {res:?}");
+        };
+        assert!(matches!(*array, Variable::SubscriptExpression(_, Some(_))));
+
+        let res = parse(r#"$items[]"#);
+        assert!(matches!(res, Ok(Variable::SubscriptExpression(_, None))));
+
+        let res = parse(r#"foo()[0]->name"#);
+        assert!(matches!(res, Ok(Variable::MemberAccessExpression(_))));
+    }
+
+    #[test]
+    fn scoped_postfix() {
+        let res = parse(r#"$object::bar()"#);
+        assert!(matches!(
+            res,
+            Ok(Variable::Callable(CallableVariable::ScopedCallExpression(
+                ScopedCallExpression {
+                    scope: ScopeResolutionQualifier::DereferencableExpression(_),
+                    ..
+                }
+            )))
+        ));
+
+        let res = parse(r#"$object::$instance"#);
+        assert!(matches!(
+            res,
+            Ok(Variable::ScopedPropertryAccessExpression(
+                ScopeResolutionQualifier::DereferencableExpression(_),
+                _
+            ))
+        ));
+
+        let res = parse(r#"$object::VERSION"#);
+        assert!(matches!(
+            res,
+            Ok(Variable::ClassConstantAccessExpression(
+                ClassConstantAccessExpression::Name {
+                    name: Name("VERSION"),
+                    ..
+                }
+            ))
+        ));
+
+        let res = parse(r#"$object::class"#);
+        assert!(matches!(
+            res,
+            Ok(Variable::ClassConstantAccessExpression(
+                ClassConstantAccessExpression::Class { .. }
+            ))
+        ));
     }
 }
@@ -1,5 +1,10 @@
 use crate::parser::{
-    expressions::{member_call_expression::MemberCallExpression, Expression},
+    expressions::{
+        callable_creation_expression::CallableCreationExpression,
+        function_call_expression::FunctionCallExpression,
+        member_call_expression::MemberCallExpression, scoped_call_expression::ScopedCallExpression,
+        Expression,
+    },
     BoxedParser,
 };
 use chumsky::{input::ValueInput, prelude::*};
@@ -12,8 +17,9 @@ pub enum CallableVariable<'a> {
     Simple(SimpleVariable<'a>),
     SubscriptExpression,                            // TODO
     MemberCallExpression(MemberCallExpression<'a>), // TODO
-    ScopedCallExpression,                           // TODO
-    FunctionCallExpression,                         // TODO
+    ScopedCallExpression(ScopedCallExpression<'a>),
+    FunctionCallExpression(FunctionCallExpression<'a>),
+    CallableCreationExpression(CallableCreationExpression<'a>),
 }
 
 impl<'a> CallableVariable<'a> {
@@ -26,9 +32,23 @@ impl<'a> CallableVariable<'a> {
         let simple =
             SimpleVariable::parser(expression_parser.clone()).map(CallableVariable::Simple);
         let member =
-            MemberCallExpression::parser(expression_parser).map(Self::MemberCallExpression);
+            MemberCallExpression::parser(expression_parser.clone()).map(Self::MemberCallExpression);
+        let callable_creation = CallableCreationExpression::parser(expression_parser.clone())
+            .map(Self::CallableCreationExpression);
+        let scoped_call =
+            ScopedCallExpression::parser(expression_parser.clone()).map(Self::ScopedCallExpression);
+        let function_call =
+            FunctionCallExpression::parser(expression_parser).map(Self::FunctionCallExpression);
 
-        choice((member, simple)).labelled("Callable variable")
+        choice((
+            member,
+            callable_creation,
+            scoped_call,
+            function_call,
+            simple,
+        ))
+        .labelled("Callable variable")
+        .boxed()
     }
 }
 
@@ -53,6 +73,36 @@ mod tests {
         assert!(matches!(res, Ok(CallableVariable::Simple(_))));
     }
 
+    #[test]
+    fn function_call() {
+        let res = parse(r#"strlen($test)"#);
+        assert!(matches!(
+            res,
+            Ok(CallableVariable::FunctionCallExpression(_))
+        ));
+    }
+
+    #[test]
+    fn scoped_call() {
+        let res = parse(r#"static::create()"#);
+        assert!(matches!(res, Ok(CallableVariable::ScopedCallExpression(_))));
+    }
+
+    #[test]
+    fn callable_creation() {
+        let res = parse(r#"strlen(...)"#);
+        assert!(matches!(
+            res,
+            Ok(CallableVariable::CallableCreationExpression(_))
+        ));
+
+        let res = parse(r#"Foo::bar(...)"#);
+        assert!(matches!(
+            res,
+            Ok(CallableVariable::CallableCreationExpression(_))
+        ));
+    }
+
     #[test]
     fn nested() {
         let res = parse(r#"$$$test"#);